@@ -74,6 +74,10 @@ object PrettyPrinter extends ByteStringPrettyPrinter {
       "Key"
     case CLType(CLType.Variants.SimpleType(CLType.Simple.UREF)) =>
       "URef"
+    case CLType(CLType.Variants.SimpleType(CLType.Simple.F32)) =>
+      "F32"
+    case CLType(CLType.Variants.SimpleType(CLType.Simple.F64)) =>
+      "F64"
 
     case CLType(CLType.Variants.OptionType(CLType.OptionProto(innerProto))) =>
       innerProto match {
@@ -95,6 +99,9 @@ object PrettyPrinter extends ByteStringPrettyPrinter {
         case Some(t) => s"FixedList(${buildString(t)}, ${length})"
       }
 
+    case CLType(CLType.Variants.ByteArrayType(CLType.ByteArray(length))) =>
+      s"ByteArray(${length})"
+
     case CLType(CLType.Variants.ResultType(CLType.Result(okProto, errProto))) =>
       s"Result{ok: ${okProto.map(buildString).getOrElse("Unspecified type")}, err: ${errProto.map(buildString).getOrElse("Unspecified type")}"
 
@@ -117,6 +124,9 @@ object PrettyPrinter extends ByteStringPrettyPrinter {
         .map(buildString)
         .getOrElse("Unspecified type")}, ${t3Proto.map(buildString).getOrElse("Unspecified type")})"
 
+    case CLType(CLType.Variants.TupleNType(CLType.TupleN(types))) =>
+      s"Tuple(${types.map(buildString).mkString(", ")})"
+
     case CLType(CLType.Variants.AnyType(CLType.Any())) => "Any"
   }
 
@@ -56,6 +56,26 @@ export enum CLTypeTag {
     Tuple3 = 20,
     /** A value of any type. */
     Any = 21,
+    /** A 32-bit floating point number */
+    F32 = 22,
+    /** A 64-bit floating point number */
+    F64 = 23,
+    /** A 4-value tuple. */
+    Tuple4 = 24,
+    /** A 5-value tuple. */
+    Tuple5 = 25,
+    /** A 6-value tuple. */
+    Tuple6 = 26,
+    /** A 7-value tuple. */
+    Tuple7 = 27,
+    /** An 8-value tuple. */
+    Tuple8 = 28,
+    /** A 9-value tuple. */
+    Tuple9 = 29,
+    /** A 10-value tuple. */
+    Tuple10 = 30,
+    /** A fixed-length array of bytes */
+    Byte_array = 31,
 }
 
 export class CLType {
@@ -86,6 +86,10 @@ export const enum ErrorCode {
     HostBufferEmpty = 33,
     /** The host buffer has been set to a value and should be consumed first by a read operation. */
     HostBufferFull = 34,
+    /** Could not lay out an array in memory. */
+    AllocLayout = 35,
+    /** An integer doesn't map to a valid action type or key management failure. */
+    InvalidActionType = 36,
 }
 
 
@@ -153,8 +153,21 @@ fn get_named_arg_size(name: &str) -> Option<usize> {
 ///
 /// Note that this is only relevant to contracts stored on-chain since a contract deployed directly
 /// is not invoked with any arguments.
+///
+/// Reverts with [`ApiError::MissingArgument`] if the argument was not passed, or with
+/// [`ApiError::InvalidArgument`] if it cannot be parsed as a `T`.
 pub fn get_named_arg<T: FromBytes>(name: &str) -> T {
-    let arg_size = get_named_arg_size(name).unwrap_or_revert_with(ApiError::MissingArgument);
+    get_named_arg_opt(name).unwrap_or_revert_with(ApiError::MissingArgument)
+}
+
+/// Returns given named argument passed to the host for the current module invocation, or `None`
+/// if the argument was not passed.
+///
+/// This allows for optional arguments: an absent argument yields `None` rather than reverting.
+/// However, an argument which is present but cannot be parsed as a `T` still reverts with
+/// [`ApiError::InvalidArgument`].
+pub fn get_named_arg_opt<T: FromBytes>(name: &str) -> Option<T> {
+    let arg_size = get_named_arg_size(name)?;
     let arg_bytes = if arg_size > 0 {
         let res = {
             let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
@@ -176,7 +189,8 @@ pub fn get_named_arg<T: FromBytes>(name: &str) -> T {
         // Avoids allocation with 0 bytes and a call to get_named_arg
         Vec::new()
     };
-    bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument)
+    let value = bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument);
+    Some(value)
 }
 
 /// Returns the caller of the current context, i.e. the [`AccountHash`] of the account which made
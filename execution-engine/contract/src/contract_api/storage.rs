@@ -65,6 +65,47 @@ pub fn read_local<K: ToBytes, V: CLTyped + FromBytes>(
     Ok(Some(bytesrepr::deserialize(value_bytes)?))
 }
 
+/// Reads the value written by [`write_local_typed`] under `key` in the context-local partition of
+/// global state.
+///
+/// Reverts with [`ApiError::CLTypeMismatch`] if the value was recorded with a type other than
+/// `V::cl_type()`.  Values recorded with the legacy `FixedList(U8, N)` description of a byte array
+/// are accepted (see `CLType::is_legacy_equivalent`).
+pub fn read_local_typed<K: ToBytes, V: CLTyped + FromBytes>(
+    key: &K,
+) -> Result<Option<V>, bytesrepr::Error> {
+    let cl_value_bytes: Vec<u8> = match read_local(key)? {
+        Some(cl_value_bytes) => cl_value_bytes,
+        None => return Ok(None),
+    };
+    let cl_value: CLValue = bytesrepr::deserialize(cl_value_bytes)?;
+    let (cl_type, value_bytes) = cl_value.destructure();
+    if !cl_type.is_legacy_equivalent(&V::cl_type()) {
+        runtime::revert(ApiError::CLTypeMismatch)
+    }
+    Ok(Some(bytesrepr::deserialize(value_bytes)?))
+}
+
+/// Reads the value under `key` in the context-local partition of global state, or returns
+/// `default` if there is no value under `key`.
+///
+/// Reverts with [`ApiError::Read`] if the value cannot be parsed as a `V`.
+pub fn read_local_or<K: ToBytes, V: CLTyped + FromBytes>(key: &K, default: V) -> V {
+    read_local(key)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or(default)
+}
+
+/// Reads the value under `key` in the context-local partition of global state, or returns
+/// `V::default()` if there is no value under `key`.
+///
+/// Reverts with [`ApiError::Read`] if the value cannot be parsed as a `V`.
+pub fn read_local_or_default<K: ToBytes, V: CLTyped + FromBytes + Default>(key: &K) -> V {
+    read_local(key)
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_default()
+}
+
 /// Writes `value` under `uref` in the global state.
 pub fn write<T: CLTyped + ToBytes>(uref: URef, value: T) {
     let key = Key::from(uref);
@@ -90,6 +131,17 @@ pub fn write_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
     }
 }
 
+/// Writes `value` under `key` in the context-local partition of global state, along with its
+/// [`CLType`](casperlabs_types::CLType).
+///
+/// A value written this way can only be read back using [`read_local_typed`], which checks the
+/// recorded type before deserializing the value.
+pub fn write_local_typed<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
+    let cl_value = CLValue::from_t(value).unwrap_or_revert();
+    let cl_value_bytes = cl_value.into_bytes().unwrap_or_revert();
+    write_local(key, cl_value_bytes);
+}
+
 /// Adds `value` to the one currently under `uref` in the global state.
 pub fn add<T: CLTyped + ToBytes>(uref: URef, value: T) {
     let key = Key::from(uref);
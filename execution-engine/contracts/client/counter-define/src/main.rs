@@ -105,7 +105,7 @@ fn get_entry_points() -> EntryPoints {
         ENTRYPOINT_SESSION,
         vec![Parameter::new(
             ARG_CONTRACT_HASH_NAME,
-            CLType::FixedList(Box::new(CLType::U8), 32),
+            CLType::ByteArray(32),
         )],
         CLType::Unit,
         EntryPointAccess::Public,
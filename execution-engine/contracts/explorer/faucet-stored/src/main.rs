@@ -17,6 +17,7 @@ const ACCESS_KEY_NAME: &str = "faucet_package_access";
 const ENTRY_POINT_NAME: &str = "call_faucet";
 const ARG_TARGET: &str = "target";
 const ARG_AMOUNT: &str = "amount";
+const ARG_MAX_TOTAL: &str = "max_total";
 const CONTRACT_VERSION: &str = "contract_version";
 
 #[no_mangle]
@@ -33,6 +34,7 @@ fn store() -> (ContractHash, ContractVersion) {
             vec![
                 Parameter::new(ARG_TARGET, AccountHash::cl_type()),
                 Parameter::new(ARG_AMOUNT, CLType::U512),
+                Parameter::new(ARG_MAX_TOTAL, CLType::U512),
             ],
             CLType::Unit,
             EntryPointAccess::Public,
@@ -8,31 +8,48 @@ use types::{account::AccountHash, ApiError, U512};
 
 const ARG_TARGET: &str = "target";
 const ARG_AMOUNT: &str = "amount";
+const ARG_MAX_TOTAL: &str = "max_total";
+/// The amount transferred if the optional `amount` arg is not provided.
+const DEFAULT_AMOUNT: u64 = 10_000_000;
+/// The maximum total amount which can be transferred to a single account hash if the optional
+/// `max_total` arg is not provided. This is ten times the amount requested by the explorer's
+/// faucet service.
+const DEFAULT_MAX_TOTAL: u64 = 10_000_000_000;
 
 #[repr(u32)]
 enum CustomError {
-    AlreadyFunded = 1,
+    ExceedsMaxFunding = 2,
 }
 
 /// Executes token transfer to supplied account hash.
+///
+/// The `amount` arg is optional; if it is not provided, `DEFAULT_AMOUNT` is transferred.
+///
+/// An account hash can be funded multiple times, up to a cumulative total of `max_total`. The
+/// `max_total` arg is optional; if it is not provided, `DEFAULT_MAX_TOTAL` applies.
+///
 /// Revert status codes:
-/// 1 - requested transfer to already funded account hash.
+/// 2 - requested transfer would take the total funded to the account hash above `max_total`.
+///
+/// Status code 1 (previously returned for any request to fund an already-funded account hash) is
+/// no longer used.
 #[no_mangle]
 pub fn delegate() {
     let account_hash: AccountHash = runtime::get_named_arg(ARG_TARGET);
 
-    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let amount: U512 =
+        runtime::get_named_arg_opt(ARG_AMOUNT).unwrap_or_else(|| U512::from(DEFAULT_AMOUNT));
 
-    // Maybe we will decide to allow multiple funds up until some maximum value.
-    let already_funded = storage::read_local::<AccountHash, U512>(&account_hash)
-        .unwrap_or_default()
-        .is_some();
+    let max_total: U512 =
+        runtime::get_named_arg_opt(ARG_MAX_TOTAL).unwrap_or_else(|| U512::from(DEFAULT_MAX_TOTAL));
 
-    if already_funded {
-        runtime::revert(ApiError::User(CustomError::AlreadyFunded as u16));
-    } else {
-        system::transfer_to_account(account_hash, amount).unwrap_or_revert();
-        // Transfer successful; Store the fact of funding in the local state.
-        storage::write_local(account_hash, amount);
-    }
+    let funded_so_far: U512 = storage::read_local_or_default(&account_hash);
+    let new_total = funded_so_far
+        .checked_add(amount)
+        .filter(|new_total| *new_total <= max_total)
+        .unwrap_or_revert_with(ApiError::User(CustomError::ExceedsMaxFunding as u16));
+
+    system::transfer_to_account(account_hash, amount).unwrap_or_revert();
+    // Transfer successful; Store the cumulative amount funded in the local state.
+    storage::write_local(account_hash, new_total);
 }
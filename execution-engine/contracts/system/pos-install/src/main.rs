@@ -152,7 +152,7 @@ pub extern "C" fn install() {
             METHOD_FINALIZE_PAYMENT.to_string(),
             vec![
                 Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ACCOUNT_KEY, CLType::FixedList(Box::new(CLType::U8), 32)),
+                Parameter::new(ARG_ACCOUNT_KEY, CLType::ByteArray(32)),
             ],
             CLType::Unit,
             EntryPointAccess::Public,
@@ -0,0 +1,21 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::runtime;
+use types::U512;
+
+const ARG_EXPECT_PRESENT: &str = "expect_present";
+const ARG_VALUE: &str = "value";
+const EXPECTED_VALUE: u64 = 42;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let expect_present: bool = runtime::get_named_arg(ARG_EXPECT_PRESENT);
+    let maybe_value: Option<U512> = runtime::get_named_arg_opt(ARG_VALUE);
+
+    if expect_present {
+        assert_eq!(maybe_value, Some(U512::from(EXPECTED_VALUE)));
+    } else {
+        assert_eq!(maybe_value, None);
+    }
+}
@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+use core::ptr;
+
+use contract::contract_api::runtime;
+
+const ARG_ITERATIONS: &str = "iterations";
+const ARG_CALL_HOST: &str = "call_host";
+
+/// Loops the number of times given by the `iterations` arg, calling `get_blocktime` on each
+/// iteration if the `call_host` arg is `true`.
+#[no_mangle]
+pub extern "C" fn call() {
+    let iterations: u32 = runtime::get_named_arg(ARG_ITERATIONS);
+    let call_host: bool = runtime::get_named_arg(ARG_CALL_HOST);
+    for iteration in 0..iterations {
+        if call_host {
+            runtime::get_blocktime();
+        } else {
+            // Stops the otherwise empty loop from being optimized away.
+            unsafe { ptr::read_volatile(&iteration) };
+        }
+    }
+}
@@ -14,9 +14,7 @@ pub fn delegate() {
     // Appends " Hello, world!" to a [66; 32] local key with spaces trimmed.
     // Two runs should yield value "Hello, world! Hello, world!"
     // read from local state
-    let mut res: String = storage::read_local(&LOCAL_KEY)
-        .unwrap_or_default()
-        .unwrap_or_default();
+    let mut res: String = storage::read_local_or_default(&LOCAL_KEY);
 
     res.push_str(HELLO_PREFIX);
     // Write "Hello, "
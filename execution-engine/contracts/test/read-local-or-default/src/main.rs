@@ -0,0 +1,34 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_READ_WRONG_TYPE: &str = "read_wrong_type";
+const MISSING_KEY: [u8; 32] = [1u8; 32];
+const PRESENT_KEY: [u8; 32] = [2u8; 32];
+const WRONG_TYPE_KEY: [u8; 32] = [3u8; 32];
+const DEFAULT_VALUE: u64 = 7;
+const STORED_VALUE: u64 = 5;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let missing: u64 = storage::read_local_or_default(&MISSING_KEY);
+    assert_eq!(missing, 0);
+    assert_eq!(storage::read_local_or(&MISSING_KEY, DEFAULT_VALUE), DEFAULT_VALUE);
+
+    storage::write_local(PRESENT_KEY, STORED_VALUE);
+    let present: u64 = storage::read_local_or_default(&PRESENT_KEY);
+    assert_eq!(present, STORED_VALUE);
+    assert_eq!(storage::read_local_or(&PRESENT_KEY, DEFAULT_VALUE), STORED_VALUE);
+
+    let read_wrong_type: bool = runtime::get_named_arg_opt(ARG_READ_WRONG_TYPE).unwrap_or_default();
+    if read_wrong_type {
+        storage::write_local(WRONG_TYPE_KEY, String::from("not a u64"));
+        // Should revert with `ApiError::Read` rather than returning the default.
+        let _value: u64 = storage::read_local_or_default(&WRONG_TYPE_KEY);
+    }
+}
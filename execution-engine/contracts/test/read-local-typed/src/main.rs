@@ -0,0 +1,32 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+const ARG_READ_WRONG_TYPE: &str = "read_wrong_type";
+const MISSING_KEY: [u8; 32] = [1u8; 32];
+const PRESENT_KEY: [u8; 32] = [2u8; 32];
+const STORED_VALUE: u64 = 5;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let missing: Option<u64> = storage::read_local_typed(&MISSING_KEY).unwrap_or_revert();
+    assert_eq!(missing, None);
+
+    storage::write_local_typed(PRESENT_KEY, STORED_VALUE);
+    let present: Option<u64> = storage::read_local_typed(&PRESENT_KEY).unwrap_or_revert();
+    assert_eq!(present, Some(STORED_VALUE));
+
+    let read_wrong_type: bool = runtime::get_named_arg_opt(ARG_READ_WRONG_TYPE).unwrap_or_default();
+    if read_wrong_type {
+        // Should revert with `ApiError::CLTypeMismatch`.
+        let _value: Option<String> = storage::read_local_typed(&PRESENT_KEY).unwrap_or_revert();
+    }
+}
@@ -34,7 +34,7 @@ pub extern "C" fn call() {
         let entry_point = EntryPoint::new(
             ENTRY_FUNCTION_NAME.to_string(),
             vec![
-                Parameter::new(ARG_0_NAME, CLType::FixedList(Box::new(CLType::U8), 32)),
+                Parameter::new(ARG_0_NAME, CLType::ByteArray(32)),
                 Parameter::new(ARG_1_NAME, CLType::U512),
             ],
             CLType::Unit,
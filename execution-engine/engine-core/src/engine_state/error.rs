@@ -21,6 +21,8 @@ pub enum Error {
     InvalidUpgradeConfig,
     #[fail(display = "Wasm preprocessing error: {}", _0)]
     WasmPreprocessing(engine_wasm_prep::PreprocessingError),
+    #[fail(display = "Invalid wasm costs: {}", _0)]
+    InvalidWasmCosts(engine_wasm_prep::wasm_costs::WasmCostsError),
     #[fail(display = "Wasm serialization error: {:?}", _0)]
     WasmSerialization(parity_wasm::SerializationError),
     #[fail(display = "{}", _0)]
@@ -55,6 +57,12 @@ impl From<engine_wasm_prep::PreprocessingError> for Error {
     }
 }
 
+impl From<engine_wasm_prep::wasm_costs::WasmCostsError> for Error {
+    fn from(error: engine_wasm_prep::wasm_costs::WasmCostsError) -> Self {
+        Error::InvalidWasmCosts(error)
+    }
+}
+
 impl From<parity_wasm::SerializationError> for Error {
     fn from(error: parity_wasm::SerializationError) -> Self {
         Error::WasmSerialization(error)
@@ -272,6 +272,9 @@ impl Distribution<ExecConfig> for Standard {
             max_stack_height: rng.gen(),
             opcodes_mul: rng.gen(),
             opcodes_div: rng.gen(),
+            control_flow: rng.gen(),
+            bit: rng.gen(),
+            host_function_base: rng.gen(),
         };
 
         ExecConfig {
@@ -173,6 +173,7 @@ where
 
         let initial_root_hash = self.state.empty_root();
         let wasm_costs = ee_config.wasm_costs();
+        wasm_costs.validate()?;
         let preprocessor = Preprocessor::new(wasm_costs);
 
         // Spec #3: Create "virtual system account" object.
@@ -534,6 +535,7 @@ where
             Some(new_wasm_costs) => new_wasm_costs,
             None => *current_protocol_data.wasm_costs(),
         };
+        new_wasm_costs.validate()?;
 
         // 3.1.2.2 persist wasm CostTable
         let mut new_protocol_data = ProtocolData::new(
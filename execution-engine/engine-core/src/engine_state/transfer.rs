@@ -139,8 +139,9 @@ impl TransferRuntimeArgsBuilder {
                 Ok(TransferTargetMode::PurseExists(uref))
             }
             Some(cl_value)
-                if *cl_value.cl_type()
-                    == types::CLType::FixedList(Box::new(types::CLType::U8), 32) =>
+                if cl_value
+                    .cl_type()
+                    .is_legacy_equivalent(&types::CLType::ByteArray(32)) =>
             {
                 let account_key: Key = {
                     let hash = match cl_value.clone().into_t() {
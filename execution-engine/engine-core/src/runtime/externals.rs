@@ -28,6 +28,10 @@ where
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
+        // The gas function is the metering hook itself, so isn't charged as a host function call.
+        if func != FunctionIndex::GasFuncIndex {
+            self.charge_host_function_call()?;
+        }
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
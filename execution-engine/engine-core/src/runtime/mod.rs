@@ -146,11 +146,14 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
         | CLType::U8
         | CLType::U32
         | CLType::U64
+        | CLType::F32
+        | CLType::F64
         | CLType::U128
         | CLType::U256
         | CLType::U512
         | CLType::Unit
         | CLType::String
+        | CLType::ByteArray(_)
         | CLType::Any => Ok(vec![]),
         CLType::Option(ty) => match **ty {
             CLType::URef => {
@@ -1331,8 +1334,15 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
             }
             (_, _) => Ok(vec![]),
         },
-        // TODO: nested matches for Tuple3?
-        CLType::Tuple3(_) => Ok(vec![]),
+        // TODO: nested matches for Tuple3 and larger tuples?
+        CLType::Tuple3(_)
+        | CLType::Tuple4(_)
+        | CLType::Tuple5(_)
+        | CLType::Tuple6(_)
+        | CLType::Tuple7(_)
+        | CLType::Tuple8(_)
+        | CLType::Tuple9(_)
+        | CLType::Tuple10(_) => Ok(vec![]),
         CLType::Key => {
             let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
             Ok(key.into_uref().into_iter().collect())
@@ -1388,16 +1398,7 @@ where
     /// Intuition about the return value sense is to answer the question 'are we
     /// allowed to continue?'
     fn charge_gas(&mut self, amount: Gas) -> bool {
-        let prev = self.context.gas_counter();
-        match prev.checked_add(amount) {
-            // gas charge overflow protection
-            None => false,
-            Some(val) if val > self.context.gas_limit() => false,
-            Some(val) => {
-                self.context.set_gas_counter(val);
-                true
-            }
-        }
+        self.context.charge_gas(amount).is_ok()
     }
 
     fn gas(&mut self, amount: Gas) -> Result<(), Trap> {
@@ -1408,6 +1409,13 @@ where
         }
     }
 
+    /// Charges the base cost of a call to a host function, as set in the current protocol's
+    /// Wasm costs.
+    fn charge_host_function_call(&mut self) -> Result<(), Trap> {
+        let host_function_base = self.context.protocol_data().wasm_costs().host_function_base;
+        self.gas(Gas::new(host_function_base.into()))
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -1985,7 +1993,7 @@ where
             .cloned()
             .zip(args.to_values().into_iter().map(|v| v.cl_type()).cloned())
         {
-            if expected != found {
+            if !found.is_legacy_equivalent(&expected) {
                 return Err(Error::type_mismatch(expected, found));
             }
         }
@@ -3384,6 +3392,8 @@ mod tests {
                 | CLType::U8
                 | CLType::U32
                 | CLType::U64
+                | CLType::F32
+                | CLType::F64
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
@@ -3394,11 +3404,19 @@ mod tests {
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
+                | CLType::ByteArray(_)
                 | CLType::Result { .. }
                 | CLType::Map { .. }
                 | CLType::Tuple1(_)
                 | CLType::Tuple2(_)
                 | CLType::Tuple3(_)
+                | CLType::Tuple4(_)
+                | CLType::Tuple5(_)
+                | CLType::Tuple6(_)
+                | CLType::Tuple7(_)
+                | CLType::Tuple8(_)
+                | CLType::Tuple9(_)
+                | CLType::Tuple10(_)
                 | CLType::Any => (),
             }
         };
@@ -3412,6 +3430,8 @@ mod tests {
             any::<u8>().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             any::<u32>().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             any::<u64>().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
+            any::<f32>().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
+            any::<f64>().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             u128_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             u256_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             u512_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
@@ -281,6 +281,29 @@ where
         self.gas_counter = new_gas_counter;
     }
 
+    /// Returns the gas still available before the limit is reached, or zero if the counter has
+    /// somehow exceeded the limit.
+    pub fn remaining_gas(&self) -> Gas {
+        self.gas_limit
+            .checked_sub(self.gas_counter)
+            .unwrap_or_default()
+    }
+
+    /// Adds `amount` to the gas counter, returning the gas remaining afterwards.
+    ///
+    /// Returns [`Error::GasLimit`] and leaves the counter unchanged if the charge would exceed the
+    /// gas limit.
+    pub fn charge_gas(&mut self, amount: Gas) -> Result<Gas, Error> {
+        match self.gas_counter.checked_add(amount) {
+            Some(new_gas_counter) if new_gas_counter <= self.gas_limit => {
+                self.gas_counter = new_gas_counter;
+                Ok(self.remaining_gas())
+            }
+            // Exceeded the limit, or overflowed.
+            _ => Err(Error::GasLimit),
+        }
+    }
+
     pub fn base_key(&self) -> Key {
         self.base_key
     }
@@ -494,6 +517,8 @@ where
                 | CLType::U8
                 | CLType::U32
                 | CLType::U64
+                | CLType::F32
+                | CLType::F64
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
@@ -502,10 +527,18 @@ where
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
+                | CLType::ByteArray(_)
                 | CLType::Result { .. }
                 | CLType::Map { .. }
                 | CLType::Tuple1(_)
                 | CLType::Tuple3(_)
+                | CLType::Tuple4(_)
+                | CLType::Tuple5(_)
+                | CLType::Tuple6(_)
+                | CLType::Tuple7(_)
+                | CLType::Tuple8(_)
+                | CLType::Tuple9(_)
+                | CLType::Tuple10(_)
                 | CLType::Any => Ok(()),
                 CLType::Key => {
                     let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
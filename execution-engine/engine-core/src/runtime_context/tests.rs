@@ -25,7 +25,7 @@ use types::{
     },
     contracts::NamedKeys,
     AccessRights, BlockTime, CLValue, Contract, EntryPointType, EntryPoints, Key, Phase,
-    ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH,
+    ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
 };
 
 use super::{Address, Error, RuntimeContext};
@@ -116,6 +116,7 @@ fn mock_runtime_context<'a>(
     access_rights: HashMap<Address, HashSet<AccessRights>>,
     hash_address_generator: AddressGenerator,
     uref_address_generator: AddressGenerator,
+    gas_limit: Gas,
 ) -> RuntimeContext<'a, InMemoryGlobalStateView> {
     let tracking_copy = mock_tracking_copy(base_key, account.clone());
     RuntimeContext::new(
@@ -129,7 +130,7 @@ fn mock_runtime_context<'a>(
         base_key,
         BlockTime::new(0),
         [1u8; 32],
-        Gas::default(),
+        gas_limit,
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
@@ -176,6 +177,7 @@ where
         access_rights,
         hash_address_generator,
         uref_address_generator,
+        Gas::default(),
     );
     query(runtime_context)
 }
@@ -778,6 +780,7 @@ fn remove_uref_works() {
         access_rights,
         hash_address_generator,
         uref_address_generator,
+        Gas::default(),
     );
 
     assert!(runtime_context.named_keys_contains_key(&uref_name));
@@ -810,6 +813,7 @@ fn validate_valid_purse_of_an_account() {
         access_rights,
         hash_address_generator,
         uref_address_generator,
+        Gas::default(),
     );
 
     // URef that has the same id as purse of an account gets validated
@@ -831,3 +835,46 @@ fn validate_valid_purse_of_an_account() {
     let purse = URef::new([53; 32], AccessRights::READ_ADD_WRITE);
     assert!(runtime_context.validate_uref(&purse).is_err());
 }
+
+#[test]
+fn should_charge_gas_up_to_limit() {
+    let deploy_hash = [1u8; 32];
+    let (base_key, account) = mock_account(AccountHash::new([0u8; 32]));
+    let mut named_keys = NamedKeys::new();
+    let mut runtime_context = mock_runtime_context(
+        &account,
+        base_key,
+        &mut named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        Gas::new(U512::from(10)),
+    );
+    assert_eq!(runtime_context.remaining_gas(), Gas::new(U512::from(10)));
+
+    let remaining = runtime_context.charge_gas(Gas::new(U512::from(4))).unwrap();
+    assert_eq!(remaining, Gas::new(U512::from(6)));
+    assert_eq!(runtime_context.gas_counter(), Gas::new(U512::from(4)));
+
+    // Charging beyond the limit fails and leaves the counter unchanged.
+    match runtime_context.charge_gas(Gas::new(U512::from(7))) {
+        Err(Error::GasLimit) => (),
+        other => panic!("expected GasLimit error, got {:?}", other),
+    }
+    assert_eq!(runtime_context.gas_counter(), Gas::new(U512::from(4)));
+
+    // Charging exactly up to the limit succeeds.
+    let remaining = runtime_context.charge_gas(Gas::new(U512::from(6))).unwrap();
+    assert_eq!(remaining, Gas::new(U512::zero()));
+    assert_eq!(runtime_context.remaining_gas(), Gas::new(U512::zero()));
+
+    // Overflowing the counter fails rather than panicking.
+    match runtime_context.charge_gas(Gas::new(U512::max_value())) {
+        Err(Error::GasLimit) => (),
+        other => panic!("expected GasLimit error, got {:?}", other),
+    }
+
+    // If the counter ever exceeds the limit, the remaining gas saturates at zero.
+    runtime_context.set_gas_counter(Gas::new(U512::from(11)));
+    assert_eq!(runtime_context.remaining_gas(), Gas::new(U512::zero()));
+}
@@ -15,6 +15,9 @@ impl From<WasmCosts> for ChainSpec_CostTable_WasmCosts {
             max_stack_height: wasm_costs.max_stack_height,
             opcodes_mul: wasm_costs.opcodes_mul,
             opcodes_div: wasm_costs.opcodes_div,
+            control_flow: wasm_costs.control_flow,
+            bit: wasm_costs.bit,
+            host_function_base: wasm_costs.host_function_base,
             ..Default::default()
         }
     }
@@ -22,6 +25,16 @@ impl From<WasmCosts> for ChainSpec_CostTable_WasmCosts {
 
 impl From<ChainSpec_CostTable_WasmCosts> for WasmCosts {
     fn from(pb_wasm_costs: ChainSpec_CostTable_WasmCosts) -> Self {
+        // Protobuf doesn't distinguish an unset field from zero, so zero `control_flow` and `bit`
+        // costs are treated as unset and take the value of `regular`, as they did before these
+        // fields existed.
+        let regular_if_unset = |cost: u32| {
+            if cost == 0 {
+                pb_wasm_costs.regular
+            } else {
+                cost
+            }
+        };
         WasmCosts {
             regular: pb_wasm_costs.regular,
             div: pb_wasm_costs.div,
@@ -33,13 +46,16 @@ impl From<ChainSpec_CostTable_WasmCosts> for WasmCosts {
             max_stack_height: pb_wasm_costs.max_stack_height,
             opcodes_mul: pb_wasm_costs.opcodes_mul,
             opcodes_div: pb_wasm_costs.opcodes_div,
+            control_flow: regular_if_unset(pb_wasm_costs.control_flow),
+            bit: regular_if_unset(pb_wasm_costs.bit),
+            host_function_base: pb_wasm_costs.host_function_base,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use proptest::proptest;
+    use proptest::{prop_assume, proptest};
 
     use engine_wasm_prep::wasm_costs::gens;
 
@@ -49,7 +65,32 @@ mod tests {
     proptest! {
         #[test]
         fn round_trip(wasm_costs in gens::wasm_costs_arb()) {
+            // Zero `control_flow` and `bit` costs are read back as `regular`.
+            prop_assume!(wasm_costs.control_flow != 0 && wasm_costs.bit != 0);
             test_utils::protobuf_round_trip::<WasmCosts, ChainSpec_CostTable_WasmCosts>(wasm_costs);
         }
     }
+
+    #[test]
+    fn should_price_unset_control_flow_and_bit_as_regular() {
+        let pb_wasm_costs = ChainSpec_CostTable_WasmCosts {
+            regular: 3,
+            control_flow: 0,
+            bit: 0,
+            ..Default::default()
+        };
+        let wasm_costs = WasmCosts::from(pb_wasm_costs);
+        assert_eq!(wasm_costs.control_flow, 3);
+        assert_eq!(wasm_costs.bit, 3);
+
+        let pb_wasm_costs = ChainSpec_CostTable_WasmCosts {
+            regular: 3,
+            control_flow: 5,
+            bit: 7,
+            ..Default::default()
+        };
+        let wasm_costs = WasmCosts::from(pb_wasm_costs);
+        assert_eq!(wasm_costs.control_flow, 5);
+        assert_eq!(wasm_costs.bit, 7);
+    }
 }
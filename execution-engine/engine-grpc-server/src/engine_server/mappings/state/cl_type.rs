@@ -1,4 +1,7 @@
-use std::convert::{TryFrom, TryInto};
+use std::{
+    collections::VecDeque,
+    convert::{TryFrom, TryInto},
+};
 
 use types::CLType;
 
@@ -17,6 +20,8 @@ impl From<CLType> for state::CLType {
             CLType::U8 => pb_type.set_simple_type(state::CLType_Simple::U8),
             CLType::U32 => pb_type.set_simple_type(state::CLType_Simple::U32),
             CLType::U64 => pb_type.set_simple_type(state::CLType_Simple::U64),
+            CLType::F32 => pb_type.set_simple_type(state::CLType_Simple::F32),
+            CLType::F64 => pb_type.set_simple_type(state::CLType_Simple::F64),
             CLType::U128 => pb_type.set_simple_type(state::CLType_Simple::U128),
             CLType::U256 => pb_type.set_simple_type(state::CLType_Simple::U256),
             CLType::U512 => pb_type.set_simple_type(state::CLType_Simple::U512),
@@ -35,6 +40,7 @@ impl From<CLType> for state::CLType {
                 pb_fixed_list.set_inner((*inner).into());
                 pb_fixed_list.set_len(len);
             }
+            CLType::ByteArray(len) => pb_type.mut_byte_array_type().set_len(len),
             CLType::Result { ok, err } => {
                 let pb_result = pb_type.mut_result_type();
                 pb_result.set_ok((*ok).into());
@@ -64,6 +70,13 @@ impl From<CLType> for state::CLType {
                 pb_tuple3.set_type1((*types[1].clone()).into());
                 pb_tuple3.set_type2((*types[2].clone()).into());
             }
+            CLType::Tuple4(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple5(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple6(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple7(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple8(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple9(types) => set_tuple_n_types(&mut pb_type, &types),
+            CLType::Tuple10(types) => set_tuple_n_types(&mut pb_type, &types),
             CLType::Any => {
                 let _pb_any = pb_type.mut_any_type();
             }
@@ -87,6 +100,8 @@ impl TryFrom<state::CLType> for CLType {
             CLType_oneof_variants::simple_type(CLType_Simple::U8) => CLType::U8,
             CLType_oneof_variants::simple_type(CLType_Simple::U32) => CLType::U32,
             CLType_oneof_variants::simple_type(CLType_Simple::U64) => CLType::U64,
+            CLType_oneof_variants::simple_type(CLType_Simple::F32) => CLType::F32,
+            CLType_oneof_variants::simple_type(CLType_Simple::F64) => CLType::F64,
             CLType_oneof_variants::simple_type(CLType_Simple::U128) => CLType::U128,
             CLType_oneof_variants::simple_type(CLType_Simple::U256) => CLType::U256,
             CLType_oneof_variants::simple_type(CLType_Simple::U512) => CLType::U512,
@@ -106,6 +121,9 @@ impl TryFrom<state::CLType> for CLType {
                 let inner = pb_fixed_list.take_inner().try_into()?;
                 CLType::FixedList(Box::new(inner), pb_fixed_list.len)
             }
+            CLType_oneof_variants::byte_array_type(pb_byte_array) => {
+                CLType::ByteArray(pb_byte_array.len)
+            }
             CLType_oneof_variants::result_type(mut pb_result) => {
                 let ok = pb_result.take_ok().try_into()?;
                 let err = pb_result.take_err().try_into()?;
@@ -137,8 +155,70 @@ impl TryFrom<state::CLType> for CLType {
                 let type2 = pb_tuple3.take_type2().try_into()?;
                 CLType::Tuple3([Box::new(type0), Box::new(type1), Box::new(type2)])
             }
+            CLType_oneof_variants::tuple_n_type(mut pb_tuple_n) => {
+                let mut types = VecDeque::new();
+                for pb_inner in pb_tuple_n.take_types() {
+                    let inner: CLType = pb_inner.try_into()?;
+                    types.push_back(Box::new(inner));
+                }
+                let len = types.len();
+                let mut next = || types.pop_front().unwrap();
+                match len {
+                    4 => CLType::Tuple4([next(), next(), next(), next()]),
+                    5 => CLType::Tuple5([next(), next(), next(), next(), next()]),
+                    6 => CLType::Tuple6([next(), next(), next(), next(), next(), next()]),
+                    7 => CLType::Tuple7([next(), next(), next(), next(), next(), next(), next()]),
+                    8 => CLType::Tuple8([
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                    ]),
+                    9 => CLType::Tuple9([
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                    ]),
+                    10 => CLType::Tuple10([
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                        next(),
+                    ]),
+                    _ => {
+                        return Err(ParsingError(format!(
+                            "Unable to parse Protobuf CLType tuple with {} elements",
+                            len
+                        )))
+                    }
+                }
+            }
             CLType_oneof_variants::any_type(_) => CLType::Any,
         };
         Ok(cl_type)
     }
 }
+
+fn set_tuple_n_types(pb_type: &mut state::CLType, types: &[Box<CLType>]) {
+    let pb_types = types
+        .iter()
+        .map(|cl_type| state::CLType::from((**cl_type).clone()))
+        .collect();
+    pb_type.mut_tuple_n_type().set_types(pb_types);
+}
@@ -28,11 +28,48 @@ impl Gas {
     pub fn checked_add(&self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.value()).map(Self::new)
     }
+
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.value()).map(Self::new)
+    }
+
+    /// Returns a compact rendering of the value, scaled to a "K", "M" or "G" suffix where the
+    /// magnitude warrants it and truncated to one decimal place, e.g. "1.5K".
+    ///
+    /// Use [`Gas::value`] or the `Display` impl for the full-precision value.
+    pub fn to_human_string(&self) -> String {
+        let digits = self.0.to_string();
+        let (exponent, suffix) = match digits.len() {
+            0..=3 => return digits,
+            4..=6 => (3, "K"),
+            7..=9 => (6, "M"),
+            _ => (9, "G"),
+        };
+        let (whole, fraction) = digits.split_at(digits.len() - exponent);
+        let tenths = &fraction[..1];
+        if tenths == "0" {
+            format!("{}{}", group_thousands(whole), suffix)
+        } else {
+            format!("{}.{}{}", group_thousands(whole), tenths, suffix)
+        }
+    }
+}
+
+/// Inserts a comma between each group of three digits, counting from the right.
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
 }
 
 impl fmt::Display for Gas {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.0)
+        write!(f, "{}", group_thousands(&self.0.to_string()))
     }
 }
 
@@ -124,6 +161,16 @@ mod tests {
         assert_eq!((left_gas - right_gas), expected_gas, "should be equal")
     }
 
+    #[test]
+    fn should_support_checked_add_and_sub() {
+        let max_gas = Gas::new(U512::max_value());
+        let one_gas = Gas::new(U512::one());
+        assert_eq!(one_gas.checked_add(one_gas), Some(Gas::new(U512::from(2))));
+        assert_eq!(max_gas.checked_add(one_gas), None);
+        assert_eq!(max_gas.checked_sub(one_gas), Some(Gas::new(U512::max_value() - 1)));
+        assert_eq!(one_gas.checked_sub(max_gas), None);
+    }
+
     #[test]
     fn should_be_able_to_multiply_two_instances_of_gas() {
         let left_gas = Gas::new(U512::from(100));
@@ -183,4 +230,31 @@ mod tests {
         let maybe = Gas::from_motes(motes, conv_rate);
         assert!(maybe.is_none(), "should be none due to divide by zero");
     }
+
+    #[test]
+    fn should_display_with_thousands_separators() {
+        assert_eq!(Gas::new(U512::zero()).to_string(), "0");
+        assert_eq!(Gas::new(U512::from(999)).to_string(), "999");
+        assert_eq!(Gas::new(U512::from(1_500)).to_string(), "1,500");
+        assert_eq!(Gas::new(U512::from(123_456)).to_string(), "123,456");
+        let above_u64_max = U512::from(u64::max_value()) + U512::one();
+        assert_eq!(
+            Gas::new(above_u64_max).to_string(),
+            "18,446,744,073,709,551,616"
+        );
+    }
+
+    #[test]
+    fn should_render_human_string_with_suffix() {
+        assert_eq!(Gas::new(U512::zero()).to_human_string(), "0");
+        assert_eq!(Gas::new(U512::from(999)).to_human_string(), "999");
+        assert_eq!(Gas::new(U512::from(1_500)).to_human_string(), "1.5K");
+        assert_eq!(Gas::new(U512::from(2_000_000)).to_human_string(), "2M");
+        assert_eq!(Gas::new(U512::from(987_654_321)).to_human_string(), "987.6M");
+        let above_u64_max = U512::from(u64::max_value()) + U512::one();
+        assert_eq!(
+            Gas::new(above_u64_max).to_human_string(),
+            "18,446,744,073.7G"
+        );
+    }
 }
@@ -2,19 +2,42 @@
 mod macros;
 
 use core::array::TryFromSliceError;
-use std::{convert::TryFrom, fmt};
+use std::{convert::TryFrom, fmt, str::FromStr};
 
 use blake2::{
     digest::{Input, VariableOutput},
     VarBlake2b,
 };
-use serde::Serialize;
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
 use types::bytesrepr::{self, FromBytes, ToBytes};
 
 pub use types::BLAKE2B_DIGEST_LENGTH;
 
+/// The maximum number of bytes in a BLAKE2b hash digest.
+pub const BLAKE2B_MAX_DIGEST_LENGTH: usize = 64;
+
+/// Computes the BLAKE2b hash digest of `data`, writing it into `output`.
+///
+/// The length of the digest is given by `output.len()`, which must be in the range
+/// `1..=BLAKE2B_MAX_DIGEST_LENGTH`.
+///
+/// # Panics
+///
+/// Panics if `output` is empty or longer than [`BLAKE2B_MAX_DIGEST_LENGTH`].
+pub fn blake2b_into<T: AsRef<[u8]>>(data: T, output: &mut [u8]) {
+    let mut hasher = VarBlake2b::new(output.len()).unwrap_or_else(|_| {
+        panic!(
+            "BLAKE2b digest length must be between 1 and {}, not {}",
+            BLAKE2B_MAX_DIGEST_LENGTH,
+            output.len()
+        )
+    });
+    hasher.input(data);
+    hasher.variable_result(|hash| output.clone_from_slice(hash));
+}
+
 /// Represents a 32-byte BLAKE2b hash digest
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Blake2bHash([u8; BLAKE2B_DIGEST_LENGTH]);
@@ -22,12 +45,29 @@ pub struct Blake2bHash([u8; BLAKE2B_DIGEST_LENGTH]);
 impl Blake2bHash {
     /// Creates a 32-byte BLAKE2b hash digest from a given a piece of data
     pub fn new(data: &[u8]) -> Self {
-        let mut ret = [0u8; BLAKE2B_DIGEST_LENGTH];
-        // Safe to unwrap here because our digest length is constant and valid
-        let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).unwrap();
-        hasher.input(data);
-        hasher.variable_result(|hash| ret.clone_from_slice(hash));
-        Blake2bHash(ret)
+        let mut hasher = Blake2bHasher::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Computes the hash of a parent from the hashes of its two children.
+    ///
+    /// The result is the BLAKE2b hash of the 64 bytes `left.value() || right.value()`, i.e. the
+    /// left child's digest followed by the right child's.
+    pub fn combine(left: &Blake2bHash, right: &Blake2bHash) -> Self {
+        Blake2bHash::combine_many(&[*left, *right])
+    }
+
+    /// Computes the BLAKE2b hash of the concatenation of the given hashes' values, in order.
+    ///
+    /// `combine_many(&[left, right])` is equal to `combine(&left, &right)`, and an empty slice
+    /// yields the hash of no data.
+    pub fn combine_many(hashes: &[Blake2bHash]) -> Self {
+        let mut hasher = Blake2bHasher::new();
+        for hash in hashes {
+            hasher.update(&hash.0);
+        }
+        hasher.finalize()
     }
 
     /// Returns the underlying BLKAE2b hash bytes
@@ -41,6 +81,41 @@ impl Blake2bHash {
     }
 }
 
+/// An incremental hasher producing a 32-byte BLAKE2b hash digest.
+///
+/// Allows the digest of data supplied in several chunks to be computed without first concatenating
+/// the chunks.
+pub struct Blake2bHasher {
+    inner: VarBlake2b,
+}
+
+impl Blake2bHasher {
+    /// Creates a new hasher
+    pub fn new() -> Self {
+        let inner = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH)
+            .expect("BLAKE2B_DIGEST_LENGTH should be a valid BLAKE2b digest length");
+        Blake2bHasher { inner }
+    }
+
+    /// Feeds `data` into the hasher
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.input(data);
+    }
+
+    /// Consumes the hasher, returning the digest of all the data fed into it
+    pub fn finalize(self) -> Blake2bHash {
+        let mut ret = [0u8; BLAKE2B_DIGEST_LENGTH];
+        self.inner.variable_result(|hash| ret.clone_from_slice(hash));
+        Blake2bHash(ret)
+    }
+}
+
+impl Default for Blake2bHasher {
+    fn default() -> Self {
+        Blake2bHasher::new()
+    }
+}
+
 impl core::fmt::LowerHex for Blake2bHash {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         let hex_string = base16::encode_lower(&self.value());
@@ -75,6 +150,71 @@ impl core::fmt::Debug for Blake2bHash {
     }
 }
 
+/// Error while parsing a [`Blake2bHash`] from a hex string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Blake2bHashParseError {
+    /// The string (excluding any `0x` prefix) isn't 64 characters long; contains the actual
+    /// length.
+    InvalidLength(usize),
+    /// The string isn't valid base16.
+    InvalidHex(base16::DecodeError),
+}
+
+impl fmt::Display for Blake2bHashParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Blake2bHashParseError::InvalidLength(length) => write!(
+                f,
+                "expected {} hex characters but found {}",
+                BLAKE2B_DIGEST_LENGTH * 2,
+                length
+            ),
+            Blake2bHashParseError::InvalidHex(error) => write!(f, "invalid hex: {:?}", error),
+        }
+    }
+}
+
+impl FromStr for Blake2bHash {
+    type Err = Blake2bHashParseError;
+
+    /// Parses 64 hex characters, optionally prefixed with `0x`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let hex = if input.starts_with("0x") {
+            &input[2..]
+        } else {
+            input
+        };
+        if hex.len() != BLAKE2B_DIGEST_LENGTH * 2 {
+            return Err(Blake2bHashParseError::InvalidLength(hex.len()));
+        }
+        let mut bytes = [0u8; BLAKE2B_DIGEST_LENGTH];
+        let _bytes_written =
+            base16::decode_slice(hex, &mut bytes).map_err(Blake2bHashParseError::InvalidHex)?;
+        Ok(Blake2bHash(bytes))
+    }
+}
+
+impl Serialize for Blake2bHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            base16::encode_lower(&self.0).serialize(serializer)
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Blake2bHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            hex.parse().map_err(SerdeError::custom)
+        } else {
+            <[u8; BLAKE2B_DIGEST_LENGTH]>::deserialize(deserializer).map(Blake2bHash)
+        }
+    }
+}
+
 impl From<[u8; BLAKE2B_DIGEST_LENGTH]> for Blake2bHash {
     fn from(arr: [u8; BLAKE2B_DIGEST_LENGTH]) -> Self {
         Blake2bHash(arr)
@@ -111,7 +251,7 @@ impl FromBytes for Blake2bHash {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize)]
 pub struct CorrelationId(Uuid);
 
 impl CorrelationId {
@@ -119,6 +259,16 @@ impl CorrelationId {
         CorrelationId(Uuid::new_v4())
     }
 
+    /// Returns the nil (all zeros) correlation id.
+    pub fn nil() -> CorrelationId {
+        CorrelationId(Uuid::nil())
+    }
+
+    /// Returns a correlation id wrapping the given `uuid`.
+    pub fn from_uuid(uuid: Uuid) -> CorrelationId {
+        CorrelationId(uuid)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_nil()
     }
@@ -130,12 +280,27 @@ impl fmt::Display for CorrelationId {
     }
 }
 
+impl FromStr for CorrelationId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s).map(CorrelationId)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        newtypes::{Blake2bHash, CorrelationId},
+        newtypes::{
+            blake2b_into, Blake2bHash, Blake2bHashParseError, Blake2bHasher, CorrelationId,
+            BLAKE2B_MAX_DIGEST_LENGTH,
+        },
         utils,
     };
+    use blake2::{
+        digest::{Input, VariableOutput},
+        VarBlake2b,
+    };
     use std::hash::{Hash, Hasher};
 
     #[test]
@@ -213,6 +378,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_create_nil_id() {
+        assert!(CorrelationId::nil().is_empty());
+        assert_ne!(CorrelationId::new(), CorrelationId::nil());
+        assert_eq!(CorrelationId::nil(), CorrelationId::default());
+    }
+
+    #[test]
+    fn should_parse_from_string() {
+        let correlation_id = CorrelationId::new();
+        assert_eq!(correlation_id.to_string().parse(), Ok(correlation_id));
+        assert_eq!("00000000-0000-0000-0000-000000000000".parse(), Ok(CorrelationId::nil()));
+        assert!("not a uuid".parse::<CorrelationId>().is_err());
+    }
+
     #[test]
     fn should_support_clone() {
         let correlation_id = CorrelationId::new();
@@ -274,6 +454,106 @@ mod tests {
         )
     }
 
+    #[test]
+    fn blake2b_into_should_match_64_byte_reference_vector() {
+        // BLAKE2b-512("abc") from RFC 7693, Appendix A.
+        let expected = "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+                        7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923";
+        let mut digest = [0u8; BLAKE2B_MAX_DIGEST_LENGTH];
+        blake2b_into(b"abc", &mut digest);
+        assert_eq!(base16::encode_lower(&digest[..]), expected);
+    }
+
+    #[test]
+    fn blake2bhash_new_should_be_unchanged() {
+        let data = b"some data to hash";
+        let mut expected = [0u8; 32];
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.input(&data[..]);
+        hasher.variable_result(|hash| expected.clone_from_slice(hash));
+        assert_eq!(Blake2bHash::new(data).value(), expected);
+    }
+
+    #[test]
+    fn blake2bhasher_chunked_updates_should_match_single_hash() {
+        let chunks: [&[u8]; 4] = [b"some ", b"", b"data to ", b"hash"];
+        let concatenated = chunks.concat();
+
+        let mut hasher = Blake2bHasher::new();
+        for chunk in chunks.iter() {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), Blake2bHash::new(&concatenated));
+        assert_eq!(Blake2bHasher::new().finalize(), Blake2bHash::new(&[]));
+    }
+
+    #[test]
+    fn blake2bhash_combine_should_match_reference_digest() {
+        let left = Blake2bHash::from([1u8; 32]);
+        let right = Blake2bHash::from([2u8; 32]);
+        let third = Blake2bHash::from([3u8; 32]);
+        let from_hex = |hex: &str| hex.parse::<Blake2bHash>().unwrap();
+
+        let expected = from_hex("30b600fb1f0cc0b3f0fc28cdcb7389405a6659be81c7d5c5905725aa3a5119ce");
+        assert_eq!(Blake2bHash::combine(&left, &right), expected);
+        assert_eq!(Blake2bHash::combine_many(&[left, right]), expected);
+
+        let swapped = from_hex("abec1d2f9c43a6c16dd2de57c89d8b412563a515ef6566e731b0c5ee4cb62aba");
+        assert_eq!(Blake2bHash::combine(&right, &left), swapped);
+
+        let many = from_hex("5ec1d4b3165012ecc2386314107d7b140e1ad42a60bb336e817178a2df32244f");
+        assert_eq!(Blake2bHash::combine_many(&[left, right, third]), many);
+        assert_eq!(Blake2bHash::combine_many(&[]), Blake2bHash::new(&[]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn blake2b_into_should_panic_on_oversized_output() {
+        let mut digest = [0u8; BLAKE2B_MAX_DIGEST_LENGTH + 1];
+        blake2b_into(b"abc", &mut digest[..]);
+    }
+
+    #[test]
+    fn blake2bhash_from_str_should_round_trip() {
+        let hash = Blake2bHash::new(b"abc");
+        assert_eq!(format!("{:x}", hash).parse(), Ok(hash));
+        assert_eq!(format!("{:#x}", hash).parse(), Ok(hash));
+        assert_eq!(format!("{:X}", hash).parse(), Ok(hash));
+    }
+
+    #[test]
+    fn blake2bhash_from_str_should_reject_invalid_input() {
+        assert_eq!(
+            "0x00".parse::<Blake2bHash>(),
+            Err(Blake2bHashParseError::InvalidLength(2))
+        );
+        assert!(matches!(
+            "zz".repeat(32).parse::<Blake2bHash>(),
+            Err(Blake2bHashParseError::InvalidHex(_))
+        ));
+    }
+
+    #[test]
+    fn blake2bhash_should_serialize_as_hex_string_to_json() {
+        let hash = Blake2bHash([10u8; 32]);
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(
+            json,
+            "\"0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a\""
+        );
+        let parsed: Blake2bHash = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, hash);
+    }
+
+    #[test]
+    fn blake2bhash_should_serialize_as_bytes_to_binary() {
+        let hash = Blake2bHash::new(b"abc");
+        let serialized = bincode::serialize(&hash).unwrap();
+        assert_eq!(serialized, hash.to_vec());
+        let parsed: Blake2bHash = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(parsed, hash);
+    }
+
     #[test]
     fn alternate_should_prepend_0x() {
         let hash = Blake2bHash([0u8; 32]);
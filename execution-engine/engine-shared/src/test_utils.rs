@@ -24,6 +24,9 @@ pub fn wasm_costs_mock() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        control_flow: 1,
+        bit: 1,
+        host_function_base: 0,
     }
 }
 
@@ -39,5 +42,8 @@ pub fn wasm_costs_free() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 1,
         opcodes_div: 1,
+        control_flow: 0,
+        bit: 0,
+        host_function_base: 0,
     }
 }
@@ -123,13 +123,17 @@ impl ProtocolData {
     }
 }
 
+/// The costs which were added to [`WasmCosts`] after protocol data was first stored are serialized
+/// after the system contract hashes, so that protocol data stored before they existed can still be
+/// deserialized.
 impl ToBytes for ProtocolData {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-        ret.append(&mut self.wasm_costs.to_bytes()?);
+        ret.append(&mut self.wasm_costs.legacy_to_bytes()?);
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
         ret.append(&mut self.standard_payment.to_bytes()?);
+        ret.append(&mut self.wasm_costs.extension_to_bytes()?);
         Ok(ret)
     }
 
@@ -140,10 +144,16 @@ impl ToBytes for ProtocolData {
 
 impl FromBytes for ProtocolData {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (wasm_costs, rem) = WasmCosts::from_bytes(bytes)?;
+        let (wasm_costs, rem) = WasmCosts::legacy_from_bytes(bytes)?;
         let (mint, rem) = HashAddr::from_bytes(rem)?;
         let (proof_of_stake, rem) = HashAddr::from_bytes(rem)?;
         let (standard_payment, rem) = HashAddr::from_bytes(rem)?;
+        // Protocol data stored before the newer costs were added ends here.
+        let (wasm_costs, rem) = if rem.is_empty() {
+            (wasm_costs, rem)
+        } else {
+            wasm_costs.extension_from_bytes(rem)?
+        };
 
         Ok((
             ProtocolData {
@@ -187,7 +197,9 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
-    use engine_wasm_prep::wasm_costs::WasmCosts;
+    use engine_wasm_prep::wasm_costs::{
+        WasmCosts, WASM_COSTS_LEGACY_SERIALIZED_LENGTH, WASM_COSTS_SERIALIZED_LENGTH,
+    };
     use types::{bytesrepr, ContractHash};
 
     use super::{gens, ProtocolData};
@@ -204,6 +216,9 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            control_flow: 2,
+            bit: 1,
+            host_function_base: 1,
         }
     }
 
@@ -219,6 +234,9 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            control_flow: 0,
+            bit: 0,
+            host_function_base: 0,
         }
     }
 
@@ -252,6 +270,25 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_deserialize_legacy_layout() {
+        let wasm_costs = WasmCosts {
+            control_flow: 1,
+            bit: 1,
+            host_function_base: 0,
+            ..wasm_costs_mock()
+        };
+        let protocol_data = ProtocolData::new(wasm_costs, [1u8; 32], [2u8; 32], [3u8; 32]);
+        let mut legacy_bytes = bytesrepr::serialize(protocol_data).expect("should serialize");
+        legacy_bytes.truncate(
+            legacy_bytes.len() - WASM_COSTS_SERIALIZED_LENGTH + WASM_COSTS_LEGACY_SERIALIZED_LENGTH,
+        );
+
+        let deserialized: ProtocolData =
+            bytesrepr::deserialize(legacy_bytes).expect("should deserialize");
+        assert_eq!(deserialized, protocol_data);
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = [1u8; 32];
@@ -1,10 +1,8 @@
-mod store_ext;
 #[cfg(test)]
 pub(crate) mod tests;
 
 use types::bytesrepr::{self, FromBytes, ToBytes};
 
-pub use self::store_ext::StoreExt;
 use crate::transaction_source::{Readable, Writable};
 
 pub trait Store<K, V> {
@@ -20,9 +18,22 @@ pub trait Store<K, V> {
         K: ToBytes,
         V: FromBytes,
         Self::Error: From<T::Error>,
+    {
+        self.get_raw(txn, &key.to_bytes()?)
+    }
+
+    /// Reads the value stored under `key_bytes`, the serialized form of a key.
+    ///
+    /// This allows callers which already hold a serialized key to avoid serializing it afresh for
+    /// every read.
+    fn get_raw<T>(&self, txn: &T, key_bytes: &[u8]) -> Result<Option<V>, Self::Error>
+    where
+        T: Readable<Handle = Self::Handle>,
+        V: FromBytes,
+        Self::Error: From<T::Error>,
     {
         let handle = self.handle();
-        match txn.read(handle, &key.to_bytes()?)? {
+        match txn.read(handle, key_bytes)? {
             None => Ok(None),
             Some(value_bytes) => {
                 let value = bytesrepr::deserialize(value_bytes)?;
@@ -31,15 +42,92 @@ pub trait Store<K, V> {
         }
     }
 
+    /// Returns the size in bytes of the serialized value stored under `key`, without deserializing
+    /// it, or `None` if there is no such value.
+    fn value_size<T>(&self, txn: &T, key: &K) -> Result<Option<usize>, Self::Error>
+    where
+        T: Readable<Handle = Self::Handle>,
+        K: ToBytes,
+        Self::Error: From<T::Error>,
+    {
+        let handle = self.handle();
+        let maybe_value_bytes = txn.read(handle, &key.to_bytes()?)?;
+        Ok(maybe_value_bytes.map(|value_bytes| value_bytes.len()))
+    }
+
     fn put<T>(&self, txn: &mut T, key: &K, value: &V) -> Result<(), Self::Error>
     where
         T: Writable<Handle = Self::Handle>,
         K: ToBytes,
         V: ToBytes,
         Self::Error: From<T::Error>,
+    {
+        self.put_raw(txn, &key.to_bytes()?, value)
+    }
+
+    /// Writes `value` under `key_bytes`, the serialized form of a key.
+    fn put_raw<T>(&self, txn: &mut T, key_bytes: &[u8], value: &V) -> Result<(), Self::Error>
+    where
+        T: Writable<Handle = Self::Handle>,
+        V: ToBytes,
+        Self::Error: From<T::Error>,
     {
         let handle = self.handle();
-        txn.write(handle, &key.to_bytes()?, &value.to_bytes()?)
-            .map_err(Into::into)
+        txn.write(handle, key_bytes, &value.to_bytes()?).map_err(Into::into)
+    }
+
+    fn delete<T>(&self, txn: &mut T, key: &K) -> Result<(), Self::Error>
+    where
+        T: Writable<Handle = Self::Handle>,
+        K: ToBytes,
+        Self::Error: From<T::Error>,
+    {
+        let handle = self.handle();
+        txn.delete(handle, &key.to_bytes()?).map_err(Into::into)
+    }
+
+    /// Reads the values for each of `keys`, returning them in the same order as `keys` with `None`
+    /// for any key which is not present.
+    ///
+    /// The default implementation calls [`Store::get`] once per key; implementors may override it
+    /// with a batched lookup.
+    fn get_many<'a, T>(
+        &self,
+        txn: &T,
+        keys: impl Iterator<Item = &'a K>,
+    ) -> Result<Vec<Option<V>>, Self::Error>
+    where
+        T: Readable<Handle = Self::Handle>,
+        K: ToBytes + 'a,
+        V: FromBytes,
+        Self::Error: From<T::Error>,
+    {
+        let mut ret: Vec<Option<V>> = Vec::new();
+        for key in keys {
+            let result = self.get(txn, key)?;
+            ret.push(result)
+        }
+        Ok(ret)
+    }
+
+    /// Writes each of the key/value `pairs`.
+    ///
+    /// The default implementation calls [`Store::put`] once per pair; implementors may override it
+    /// with a batched write.
+    fn put_many<'a, T>(
+        &self,
+        txn: &mut T,
+        pairs: impl Iterator<Item = (&'a K, &'a V)>,
+    ) -> Result<(), Self::Error>
+    where
+        T: Writable<Handle = Self::Handle>,
+        K: ToBytes + 'a,
+        V: ToBytes + 'a,
+        Self::Error: From<T::Error>,
+    {
+        for (key, value) in pairs {
+            self.put(txn, key, value)?;
+        }
+        Ok(())
     }
 }
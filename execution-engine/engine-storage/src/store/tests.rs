@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use types::bytesrepr::{FromBytes, ToBytes};
 
 use crate::{
-    store::{Store, StoreExt},
+    store::Store,
     transaction_source::{Transaction, TransactionSource},
 };
 
@@ -47,3 +47,92 @@ where
     };
     Ok(Iterator::eq(items.values(), values.iter()))
 }
+
+pub fn get_many_preserves_order_succeeds<'a, K, V, X, S>(
+    transaction_source: &'a X,
+    store: &S,
+    items: BTreeMap<K, V>,
+    missing_keys: Vec<K>,
+) -> Result<bool, S::Error>
+where
+    K: ToBytes,
+    V: ToBytes + FromBytes + Clone + PartialEq,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S: Store<K, V>,
+    S::Error: From<X::Error>,
+{
+    // Query the present keys in reverse order, interleaved with the missing ones.
+    let mut keys = Vec::new();
+    let mut expected = Vec::new();
+    let mut missing_keys = missing_keys.iter();
+    for (key, value) in items.iter().rev() {
+        if let Some(missing_key) = missing_keys.next() {
+            keys.push(missing_key);
+            expected.push(None);
+        }
+        keys.push(key);
+        expected.push(Some(value.clone()));
+    }
+    for missing_key in missing_keys {
+        keys.push(missing_key);
+        expected.push(None);
+    }
+
+    let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+    store.put_many(&mut txn, items.iter())?;
+    let actual = store.get_many(&txn, keys.into_iter())?;
+    txn.commit()?;
+    Ok(actual == expected)
+}
+
+pub fn get_raw_agrees_with_get_succeeds<'a, K, V, X, S>(
+    transaction_source: &'a X,
+    store: &S,
+    items: BTreeMap<K, V>,
+) -> Result<bool, S::Error>
+where
+    K: ToBytes,
+    V: ToBytes + FromBytes + Clone + PartialEq,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S: Store<K, V>,
+    S::Error: From<X::Error>,
+{
+    let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+    for (key, value) in items.iter() {
+        store.put_raw(&mut txn, &key.to_bytes()?, value)?;
+    }
+    let mut agrees = true;
+    for (key, value) in items.iter() {
+        let from_get = store.get(&txn, key)?;
+        let from_get_raw = store.get_raw(&txn, &key.to_bytes()?)?;
+        agrees &= from_get.as_ref() == Some(value) && from_get == from_get_raw;
+    }
+    txn.commit()?;
+    Ok(agrees)
+}
+
+pub fn value_size_succeeds<'a, K, V, X, S>(
+    transaction_source: &'a X,
+    store: &S,
+    items: BTreeMap<K, V>,
+    missing_keys: Vec<K>,
+) -> Result<bool, S::Error>
+where
+    K: ToBytes,
+    V: ToBytes,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S: Store<K, V>,
+    S::Error: From<X::Error>,
+{
+    let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+    store.put_many(&mut txn, items.iter())?;
+    let mut succeeded = true;
+    for (key, value) in items.iter() {
+        succeeded &= store.value_size(&txn, key)? == Some(value.serialized_length());
+    }
+    for missing_key in missing_keys.iter() {
+        succeeded &= store.value_size(&txn, missing_key)?.is_none();
+    }
+    txn.commit()?;
+    Ok(succeeded)
+}
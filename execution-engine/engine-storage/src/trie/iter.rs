@@ -0,0 +1,93 @@
+//! An iterator over the leaves of a Merkle Trie.
+
+use std::{fmt, marker::PhantomData};
+
+use failure::Fail;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::FromBytes;
+
+use crate::{store::Store, transaction_source::Readable, trie::Trie};
+
+/// An error which can occur while iterating over the leaves of a [`Trie`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TrieIteratorError<E> {
+    /// An error from the underlying store.
+    Store(E),
+    /// A pointer (or the root hash) references a trie which is not in the store.
+    DanglingPointer(Blake2bHash),
+}
+
+impl<E: fmt::Display> fmt::Display for TrieIteratorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrieIteratorError::Store(error) => write!(f, "{}", error),
+            TrieIteratorError::DanglingPointer(hash) => {
+                write!(f, "Dangling pointer: {:x} not found in store", hash)
+            }
+        }
+    }
+}
+
+impl<E: Fail> Fail for TrieIteratorError<E> {}
+
+/// An iterator over the key-value pairs held in the leaves of a [`Trie`].
+///
+/// Leaves are yielded in order of their serialized keys. Tries are fetched from the store lazily
+/// as the iterator advances. After an error has been returned, the iterator yields `None`.
+pub struct TrieIterator<'a, 'b, K, V, T, S> {
+    store: &'a S,
+    txn: &'b T,
+    /// Hashes of the tries still to be visited, with the next one at the end.
+    pending: Vec<Blake2bHash>,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<'a, 'b, K, V, T, S> TrieIterator<'a, 'b, K, V, T, S> {
+    /// Constructs an iterator over the leaves of the trie with the given root.
+    pub fn new(store: &'a S, txn: &'b T, root: &Blake2bHash) -> Self {
+        TrieIterator {
+            store,
+            txn,
+            pending: vec![*root],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, K, V, T, S> Iterator for TrieIterator<'a, 'b, K, V, T, S>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: Store<Blake2bHash, Trie<K, V>>,
+    S::Error: From<T::Error>,
+{
+    type Item = Result<(K, V), TrieIteratorError<S::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(hash) = self.pending.pop() {
+            let trie = match self.store.get(self.txn, &hash) {
+                Ok(Some(trie)) => trie,
+                Ok(None) => {
+                    self.pending.clear();
+                    return Some(Err(TrieIteratorError::DanglingPointer(hash)));
+                }
+                Err(error) => {
+                    self.pending.clear();
+                    return Some(Err(TrieIteratorError::Store(error)));
+                }
+            };
+            match trie {
+                Trie::Leaf { key, value } => return Some(Ok((key, value))),
+                Trie::Node { pointer_block } => {
+                    // Push in reverse so that the lowest index is visited first.
+                    let entries = pointer_block.iter_entries().rev();
+                    self.pending.extend(entries.map(|(_index, pointer)| pointer.into_hash()));
+                }
+                Trie::Extension { pointer, .. } => self.pending.push(pointer.into_hash()),
+            }
+        }
+        None
+    }
+}
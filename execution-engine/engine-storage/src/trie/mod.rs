@@ -1,10 +1,14 @@
 //! Core types for a Merkle Trie
 
+use failure::Fail;
+
 use engine_shared::newtypes::{Blake2bHash, BLAKE2B_DIGEST_LENGTH};
 use types::bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
 
 #[cfg(test)]
 pub mod gens;
+pub mod iter;
+pub mod proof;
 
 #[cfg(test)]
 mod tests;
@@ -29,6 +33,27 @@ impl Pointer {
         }
     }
 
+    /// Consumes `self`, returning the hash it points to.
+    pub fn into_hash(self) -> Blake2bHash {
+        match self {
+            Pointer::LeafPointer(hash) => hash,
+            Pointer::NodePointer(hash) => hash,
+        }
+    }
+
+    /// Returns `true` if `self` points to a [`Trie::Leaf`].
+    pub fn is_leaf(&self) -> bool {
+        match self {
+            Pointer::LeafPointer(_) => true,
+            Pointer::NodePointer(_) => false,
+        }
+    }
+
+    /// Returns `true` if `self` points to a [`Trie::Node`] or [`Trie::Extension`].
+    pub fn is_node(&self) -> bool {
+        !self.is_leaf()
+    }
+
     pub fn update(&self, hash: Blake2bHash) -> Self {
         match self {
             Pointer::LeafPointer(_) => Pointer::LeafPointer(hash),
@@ -77,6 +102,14 @@ impl FromBytes for Pointer {
     }
 }
 
+/// An error returned by [`PointerBlock::merge`] when both blocks hold different pointers at the
+/// same index.
+#[derive(Debug, Fail, PartialEq, Eq)]
+#[fail(display = "Pointer blocks conflict at index {}", index)]
+pub struct PointerBlockConflict {
+    pub index: u8,
+}
+
 /// Represents the underlying structure of a node in a Merkle Trie
 #[derive(Copy, Clone)]
 pub struct PointerBlock([Option<Pointer>; RADIX]);
@@ -93,6 +126,53 @@ impl PointerBlock {
         }
         ret
     }
+
+    /// Returns the number of populated entries.
+    pub fn child_count(&self) -> usize {
+        self.0.iter().filter(|maybe_pointer| maybe_pointer.is_some()).count()
+    }
+
+    /// Returns an iterator over the populated entries and their indices, in ascending order of
+    /// index.
+    pub fn iter_entries(&self) -> impl DoubleEndedIterator<Item = (u8, &Pointer)> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_pointer)| {
+                maybe_pointer
+                    .as_ref()
+                    .map(|pointer| (index as u8, pointer))
+            })
+    }
+
+    /// Returns `true` if no entries are populated.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(Option::is_none)
+    }
+
+    /// Returns a block holding the populated entries of both `self` and `other`.
+    ///
+    /// Returns an error if both blocks populate the same index with different pointers.
+    pub fn merge(&self, other: &PointerBlock) -> Result<PointerBlock, PointerBlockConflict> {
+        let mut ret = *self;
+        for (index, pointer) in other.iter_entries() {
+            match ret.0[index as usize] {
+                Some(existing) if existing != *pointer => {
+                    return Err(PointerBlockConflict { index })
+                }
+                _ => ret.0[index as usize] = Some(*pointer),
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Copies every populated entry of `other` into `self`, replacing any existing entry at the
+    /// same index.
+    pub fn overlay(&mut self, other: &PointerBlock) {
+        for (index, pointer) in other.iter_entries() {
+            self.0[index as usize] = Some(*pointer);
+        }
+    }
 }
 
 impl From<[Option<Pointer>; RADIX]> for PointerBlock {
@@ -243,6 +323,22 @@ impl<K, V> Trie<K, V> {
             _ => None,
         }
     }
+
+    /// Returns the value if `self` is a [`Trie::Leaf`].
+    pub fn value(&self) -> Option<&V> {
+        match self {
+            Trie::Leaf { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Consumes `self`, returning its key and value if it is a [`Trie::Leaf`].
+    pub fn into_leaf(self) -> Option<(K, V)> {
+        match self {
+            Trie::Leaf { key, value } => Some((key, value)),
+            _ => None,
+        }
+    }
 }
 
 impl<K, V> ToBytes for Trie<K, V>
@@ -310,11 +406,40 @@ impl<K: FromBytes, V: FromBytes> FromBytes for Trie<K, V> {
     }
 }
 
+/// Splits `affix` at the index `at` into the head `affix[..at]`, the byte `affix[at]` and the
+/// tail `affix[at + 1..]`.
+///
+/// This is the split required when a new path diverges from an extension's affix at `at`: the head
+/// becomes the affix of a parent extension (if non-empty), the byte is the index of the existing
+/// branch in a new node, and the tail becomes the affix of a child extension (if non-empty).
+///
+/// If `at` is `affix.len()`, the whole affix is the head, and there is no byte and no tail.
+///
+/// Returns `None` if `at` is greater than `affix.len()`, or if `affix` is empty.
+pub fn split_affix(affix: &[u8], at: usize) -> Option<(&[u8], Option<u8>, &[u8])> {
+    if affix.is_empty() || at > affix.len() {
+        return None;
+    }
+    let (head, rest) = affix.split_at(at);
+    match rest.split_first() {
+        Some((byte, tail)) => Some((head, Some(*byte), tail)),
+        None => Some((head, None, rest)),
+    }
+}
+
 pub(crate) mod operations {
     use crate::trie::Trie;
     use engine_shared::newtypes::Blake2bHash;
     use types::bytesrepr::{self, ToBytes};
 
+    /// Computes the hash of the given trie, i.e. the hash of its serialized form
+    pub fn trie_hash<K: ToBytes, V: ToBytes>(
+        trie: &Trie<K, V>,
+    ) -> Result<Blake2bHash, bytesrepr::Error> {
+        let trie_bytes: Vec<u8> = trie.to_bytes()?;
+        Ok(Blake2bHash::new(&trie_bytes))
+    }
+
     /// Creates a tuple containing an empty root hash and an empty root (a node
     /// with an empty pointer block)
     pub fn create_hashed_empty_trie<K: ToBytes, V: ToBytes>(
@@ -322,7 +447,6 @@ pub(crate) mod operations {
         let root: Trie<K, V> = Trie::Node {
             pointer_block: Default::default(),
         };
-        let root_bytes: Vec<u8> = root.to_bytes()?;
-        Ok((Blake2bHash::new(&root_bytes), root))
+        Ok((trie_hash(&root)?, root))
     }
 }
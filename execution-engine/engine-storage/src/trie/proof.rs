@@ -0,0 +1,217 @@
+//! Merkle proofs of membership or absence of a key in a trie.
+
+use std::fmt;
+
+use failure::Fail;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::{self, FromBytes, ToBytes};
+
+use crate::{
+    store::Store,
+    transaction_source::Readable,
+    trie::{operations::trie_hash, Pointer, Trie},
+};
+
+/// An error which can occur while verifying a [`TrieProof`].
+#[derive(Debug, Fail, PartialEq, Eq)]
+pub enum TrieProofError {
+    /// The proof contains no tries.
+    #[fail(display = "Trie proof is empty")]
+    Empty,
+
+    /// The hash of the first trie in the proof is not the expected root hash.
+    #[fail(display = "Trie proof root does not match the expected root")]
+    RootMismatch,
+
+    /// The trie at the given position in the proof is not the one referenced by its parent.
+    #[fail(display = "Trie proof is broken at position {}", _0)]
+    BrokenLink(usize),
+
+    /// The proof ends before the path for the key terminates, or continues beyond it.
+    #[fail(display = "Trie proof has the wrong length")]
+    WrongLength,
+
+    #[fail(display = "{}", _0)]
+    BytesRepr(#[fail(cause)] bytesrepr::Error),
+}
+
+impl From<bytesrepr::Error> for TrieProofError {
+    fn from(error: bytesrepr::Error) -> Self {
+        TrieProofError::BytesRepr(error)
+    }
+}
+
+/// An error which can occur while generating a [`TrieProof`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TrieProveError<E> {
+    /// An error from the underlying store, or from serializing the key.
+    Store(E),
+    /// A pointer references a trie which is not in the store.
+    DanglingPointer(Blake2bHash),
+    /// A node was reached after the path for the key had been fully consumed.
+    PathExhausted,
+}
+
+impl<E: fmt::Display> fmt::Display for TrieProveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrieProveError::Store(error) => write!(f, "{}", error),
+            TrieProveError::DanglingPointer(hash) => {
+                write!(f, "Dangling pointer: {:x} not found in store", hash)
+            }
+            TrieProveError::PathExhausted => write!(f, "Key path exhausted before reaching a leaf"),
+        }
+    }
+}
+
+impl<E: Fail> Fail for TrieProveError<E> {}
+
+/// A proof that a given key is or is not present in a trie.
+///
+/// The proof comprises the tries on the path from the root to where the search for the key
+/// terminates: either the leaf holding the key, or the trie which shows the key to be absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieProof<K, V> {
+    key: K,
+    tries: Vec<Trie<K, V>>,
+}
+
+impl<K, V> TrieProof<K, V> {
+    /// Constructs a proof for `key` from the given tries, ordered from the root downwards.
+    pub fn new(key: K, tries: Vec<Trie<K, V>>) -> Self {
+        TrieProof { key, tries }
+    }
+
+    /// Returns the key which this proof concerns.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the tries which make up this proof, ordered from the root downwards.
+    pub fn tries(&self) -> &[Trie<K, V>] {
+        &self.tries
+    }
+}
+
+impl<K, V> TrieProof<K, V>
+where
+    K: ToBytes + Eq,
+    V: ToBytes + Clone,
+{
+    /// Verifies the proof against `expected_root`.
+    ///
+    /// Returns `Ok(Some(value))` if the proof shows the key to be present with `value`, or
+    /// `Ok(None)` if the proof shows the key to be absent.
+    pub fn verify(&self, expected_root: Blake2bHash) -> Result<Option<V>, TrieProofError> {
+        let path = self.key.to_bytes()?;
+        let root = self.tries.first().ok_or(TrieProofError::Empty)?;
+        if trie_hash(root)? != expected_root {
+            return Err(TrieProofError::RootMismatch);
+        }
+
+        let mut depth = 0;
+        for (position, trie) in self.tries.iter().enumerate() {
+            let maybe_next_hash = match trie {
+                Trie::Leaf { key, value } => {
+                    if position + 1 != self.tries.len() {
+                        return Err(TrieProofError::WrongLength);
+                    }
+                    // Keys may not match in the case of a compressed path from a Node directly to
+                    // a Leaf.
+                    return Ok(if *key == self.key {
+                        Some(value.clone())
+                    } else {
+                        None
+                    });
+                }
+                Trie::Node { pointer_block } => {
+                    let index = *path.get(depth).ok_or(TrieProofError::WrongLength)?;
+                    depth += 1;
+                    pointer_block[index as usize].map(Pointer::into_hash)
+                }
+                Trie::Extension { affix, pointer } => {
+                    if path[depth..].starts_with(affix) {
+                        depth += affix.len();
+                        Some(pointer.into_hash())
+                    } else {
+                        None
+                    }
+                }
+            };
+
+            match (maybe_next_hash, self.tries.get(position + 1)) {
+                // The key is absent, and this is the last trie of the proof.
+                (None, None) => return Ok(None),
+                (Some(next_hash), Some(next_trie)) => {
+                    if trie_hash(next_trie)? != next_hash {
+                        return Err(TrieProofError::BrokenLink(position + 1));
+                    }
+                }
+                (None, Some(_)) | (Some(_), None) => return Err(TrieProofError::WrongLength),
+            }
+        }
+        Err(TrieProofError::WrongLength)
+    }
+}
+
+/// Generates a proof of membership or absence of `key` in the trie with the given root.
+///
+/// Returns `Ok(None)` if `root` is not in the store.
+pub fn prove<K, V, T, S, E>(
+    store: &S,
+    txn: &T,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<Option<TrieProof<K, V>>, TrieProveError<E>>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: Store<Blake2bHash, Trie<K, V>>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<bytesrepr::Error>,
+{
+    let path = key
+        .to_bytes()
+        .map_err(|error| TrieProveError::Store(E::from(error)))?;
+    let mut current = match store
+        .get(txn, root)
+        .map_err(|error| TrieProveError::Store(E::from(error)))?
+    {
+        Some(root) => root,
+        None => return Ok(None),
+    };
+
+    let mut tries = Vec::new();
+    let mut depth = 0;
+    loop {
+        let maybe_next_hash = match &current {
+            Trie::Leaf { .. } => None,
+            Trie::Node { pointer_block } => {
+                let index = *path.get(depth).ok_or(TrieProveError::PathExhausted)?;
+                depth += 1;
+                pointer_block[index as usize].map(Pointer::into_hash)
+            }
+            Trie::Extension { affix, pointer } => {
+                if path[depth..].starts_with(affix) {
+                    depth += affix.len();
+                    Some(pointer.into_hash())
+                } else {
+                    None
+                }
+            }
+        };
+        tries.push(current);
+
+        match maybe_next_hash {
+            None => return Ok(Some(TrieProof::new(key.clone(), tries))),
+            Some(next_hash) => {
+                current = store
+                    .get(txn, &next_hash)
+                    .map_err(|error| TrieProveError::Store(E::from(error)))?
+                    .ok_or(TrieProveError::DanglingPointer(next_hash))?;
+            }
+        }
+    }
+}
@@ -7,6 +7,36 @@ fn radix_is_256() {
     );
 }
 
+mod pointer {
+    use engine_shared::newtypes::Blake2bHash;
+
+    use crate::trie::Pointer;
+
+    #[test]
+    fn should_distinguish_leaf_and_node_pointers() {
+        let hash = Blake2bHash::new(b"pointer");
+        let leaf_pointer = Pointer::LeafPointer(hash);
+        let node_pointer = Pointer::NodePointer(hash);
+
+        assert!(leaf_pointer.is_leaf());
+        assert!(!leaf_pointer.is_node());
+        assert!(node_pointer.is_node());
+        assert!(!node_pointer.is_leaf());
+    }
+
+    #[test]
+    fn into_hash_should_match_hash() {
+        let hash = Blake2bHash::new(b"pointer");
+        let leaf_pointer = Pointer::LeafPointer(hash);
+        let node_pointer = Pointer::NodePointer(hash);
+
+        assert_eq!(leaf_pointer.into_hash(), *leaf_pointer.hash());
+        assert_eq!(node_pointer.into_hash(), *node_pointer.hash());
+        assert_eq!(leaf_pointer.into_hash(), hash);
+        assert_eq!(node_pointer.into_hash(), hash);
+    }
+}
+
 mod pointer_block {
     use engine_shared::newtypes::Blake2bHash;
 
@@ -46,6 +76,146 @@ mod pointer_block {
         let pointer_block = PointerBlock::new();
         let _val = pointer_block[RADIX];
     }
+
+    #[test]
+    fn child_count_and_iter_entries() {
+        let leaf_pointer = Pointer::LeafPointer(Blake2bHash::new(b"leaf"));
+        let node_pointer = Pointer::NodePointer(Blake2bHash::new(b"node"));
+        let empty_pointer_block = PointerBlock::new();
+        assert!(empty_pointer_block.is_empty());
+        assert_eq!(empty_pointer_block.child_count(), 0);
+        assert_eq!(empty_pointer_block.iter_entries().next(), None);
+
+        let pointer_block = PointerBlock::from_indexed_pointers(&[
+            (255, leaf_pointer),
+            (0, node_pointer),
+            (7, leaf_pointer),
+        ]);
+        assert!(!pointer_block.is_empty());
+        assert_eq!(pointer_block.child_count(), 3);
+        assert_eq!(
+            pointer_block.iter_entries().collect::<Vec<_>>(),
+            vec![(0, &node_pointer), (7, &leaf_pointer), (255, &leaf_pointer)]
+        );
+    }
+
+    #[test]
+    fn merge_should_combine_disjoint_and_equal_entries() {
+        let leaf_pointer = Pointer::LeafPointer(Blake2bHash::new(b"leaf"));
+        let node_pointer = Pointer::NodePointer(Blake2bHash::new(b"node"));
+        let left = PointerBlock::from_indexed_pointers(&[(0, node_pointer), (7, leaf_pointer)]);
+        let right = PointerBlock::from_indexed_pointers(&[(7, leaf_pointer), (255, leaf_pointer)]);
+
+        let expected = PointerBlock::from_indexed_pointers(&[
+            (0, node_pointer),
+            (7, leaf_pointer),
+            (255, leaf_pointer),
+        ]);
+        assert_eq!(left.merge(&right), Ok(expected));
+        assert_eq!(right.merge(&left), Ok(expected));
+        assert_eq!(left.merge(&PointerBlock::new()), Ok(left));
+    }
+
+    #[test]
+    fn merge_should_fail_on_conflicting_entries() {
+        let leaf_pointer = Pointer::LeafPointer(Blake2bHash::new(b"leaf"));
+        let node_pointer = Pointer::NodePointer(Blake2bHash::new(b"node"));
+        let left = PointerBlock::from_indexed_pointers(&[(0, node_pointer), (7, leaf_pointer)]);
+        let right = PointerBlock::from_indexed_pointers(&[(7, node_pointer)]);
+
+        assert_eq!(left.merge(&right), Err(PointerBlockConflict { index: 7 }));
+    }
+
+    #[test]
+    fn overlay_should_overwrite_existing_entries() {
+        let leaf_pointer = Pointer::LeafPointer(Blake2bHash::new(b"leaf"));
+        let node_pointer = Pointer::NodePointer(Blake2bHash::new(b"node"));
+        let mut pointer_block =
+            PointerBlock::from_indexed_pointers(&[(0, node_pointer), (7, leaf_pointer)]);
+        let other = PointerBlock::from_indexed_pointers(&[(7, node_pointer), (9, leaf_pointer)]);
+
+        pointer_block.overlay(&other);
+        let expected = PointerBlock::from_indexed_pointers(&[
+            (0, node_pointer),
+            (7, node_pointer),
+            (9, leaf_pointer),
+        ]);
+        assert_eq!(pointer_block, expected);
+    }
+}
+
+mod trie {
+    use engine_shared::newtypes::Blake2bHash;
+    use types::bytesrepr::ToBytes;
+
+    use crate::trie::{
+        operations::{create_hashed_empty_trie, trie_hash},
+        Pointer, Trie,
+    };
+
+    #[test]
+    fn leaf_accessors_should_return_key_and_value() {
+        let leaf: Trie<u32, String> = Trie::leaf(1, String::from("one"));
+        assert_eq!(leaf.key(), Some(&1));
+        assert_eq!(leaf.value(), Some(&String::from("one")));
+        assert_eq!(leaf.into_leaf(), Some((1, String::from("one"))));
+    }
+
+    #[test]
+    fn leaf_accessors_should_return_none_for_node_and_extension() {
+        let pointer = Pointer::LeafPointer(Blake2bHash::new(b"leaf"));
+        let node: Trie<u32, String> = Trie::node(&[(0, pointer)]);
+        let extension: Trie<u32, String> = Trie::extension(vec![0, 1], pointer);
+
+        assert_eq!(node.key(), None);
+        assert_eq!(node.value(), None);
+        assert_eq!(node.into_leaf(), None);
+        assert_eq!(extension.key(), None);
+        assert_eq!(extension.value(), None);
+        assert_eq!(extension.into_leaf(), None);
+    }
+
+    #[test]
+    fn trie_hash_should_match_hash_of_serialized_trie() {
+        let (empty_root_hash, empty_root) = create_hashed_empty_trie::<u32, String>().unwrap();
+        assert_eq!(trie_hash(&empty_root).unwrap(), empty_root_hash);
+        assert_eq!(empty_root_hash, Blake2bHash::new(&empty_root.to_bytes().unwrap()));
+
+        let leaf: Trie<u32, String> = Trie::leaf(1, String::from("one"));
+        assert_eq!(trie_hash(&leaf).unwrap(), Blake2bHash::new(&leaf.to_bytes().unwrap()));
+    }
+}
+
+mod split_affix {
+    use crate::trie::split_affix;
+
+    #[test]
+    fn should_split_in_interior() {
+        let affix = [1, 2, 3, 4, 5];
+        assert_eq!(split_affix(&affix, 2), Some((&[1, 2][..], Some(3), &[4, 5][..])));
+    }
+
+    #[test]
+    fn should_split_at_boundaries() {
+        let affix = [1, 2, 3];
+        assert_eq!(split_affix(&affix, 0), Some((&[][..], Some(1), &[2, 3][..])));
+        assert_eq!(split_affix(&affix, 2), Some((&[1, 2][..], Some(3), &[][..])));
+        assert_eq!(split_affix(&[7], 0), Some((&[][..], Some(7), &[][..])));
+    }
+
+    #[test]
+    fn should_have_no_tail_when_split_at_end() {
+        let affix = [1, 2, 3];
+        assert_eq!(split_affix(&affix, affix.len()), Some((&affix[..], None, &[][..])));
+        assert_eq!(split_affix(&[7], 1), Some((&[7][..], None, &[][..])));
+    }
+
+    #[test]
+    fn should_not_split_beyond_end() {
+        let affix = [1, 2, 3];
+        assert_eq!(split_affix(&affix, affix.len() + 1), None);
+        assert_eq!(split_affix(&[], 0), None);
+    }
 }
 
 mod proptests {
@@ -77,3 +247,241 @@ mod proptests {
         }
     }
 }
+
+mod iter {
+    use engine_shared::newtypes::{Blake2bHash, CorrelationId};
+    use types::bytesrepr::ToBytes;
+
+    use crate::{
+        error::in_memory,
+        store::Store,
+        transaction_source::{in_memory::InMemoryEnvironment, Transaction, TransactionSource},
+        trie::{
+            iter::{TrieIterator, TrieIteratorError},
+            operations::create_hashed_empty_trie,
+            Pointer, Trie,
+        },
+        trie_store::{
+            in_memory::InMemoryTrieStore,
+            operations::{write, WriteResult},
+        },
+    };
+
+    const TEST_KEYS: [[u8; 4]; 5] = [
+        [1, 2, 3, 4],
+        [0, 0, 0, 1],
+        [1, 2, 3, 0],
+        [255, 0, 0, 0],
+        [0, 0, 0, 0],
+    ];
+
+    #[test]
+    fn should_iterate_over_leaves_in_key_order() {
+        let env = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&env, None);
+        let (mut root_hash, root) = create_hashed_empty_trie::<[u8; 4], Vec<u8>>().unwrap();
+
+        let mut txn = env.create_read_write_txn().unwrap();
+        store.put(&mut txn, &root_hash, &root).unwrap();
+        for key in TEST_KEYS.iter() {
+            let value = key.to_vec();
+            let write_result = write::<_, _, _, _, in_memory::Error>(
+                CorrelationId::new(),
+                &mut txn,
+                &store,
+                &root_hash,
+                key,
+                &value,
+            )
+            .unwrap();
+            match write_result {
+                WriteResult::Written(new_root_hash) => root_hash = new_root_hash,
+                _ => panic!("should write {:?}", key),
+            }
+        }
+        txn.commit().unwrap();
+
+        let txn = env.create_read_txn().unwrap();
+        let actual: Vec<([u8; 4], Vec<u8>)> = TrieIterator::new(&store, &txn, &root_hash)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        txn.commit().unwrap();
+
+        let mut expected: Vec<([u8; 4], Vec<u8>)> =
+            TEST_KEYS.iter().map(|key| (*key, key.to_vec())).collect();
+        expected.sort();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn should_fail_on_dangling_pointer() {
+        let env = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&env, None);
+        let missing_leaf_hash = Blake2bHash::new(b"missing leaf");
+        let root: Trie<[u8; 4], Vec<u8>> =
+            Trie::node(&[(3, Pointer::LeafPointer(missing_leaf_hash))]);
+        let root_hash = Blake2bHash::new(&root.to_bytes().unwrap());
+
+        let mut txn = env.create_read_write_txn().unwrap();
+        store.put(&mut txn, &root_hash, &root).unwrap();
+        txn.commit().unwrap();
+
+        let txn = env.create_read_txn().unwrap();
+        let mut iter: TrieIterator<[u8; 4], Vec<u8>, _, _> =
+            TrieIterator::new(&store, &txn, &root_hash);
+        assert_eq!(
+            iter.next(),
+            Some(Err(TrieIteratorError::DanglingPointer(missing_leaf_hash)))
+        );
+        assert_eq!(iter.next(), None);
+    }
+}
+
+mod proof {
+    use engine_shared::newtypes::{Blake2bHash, CorrelationId};
+    use types::bytesrepr::ToBytes;
+
+    use crate::{
+        error::in_memory,
+        store::Store,
+        transaction_source::{in_memory::InMemoryEnvironment, Transaction, TransactionSource},
+        trie::{
+            operations::create_hashed_empty_trie,
+            proof::{prove, TrieProof, TrieProofError, TrieProveError},
+            Pointer, Trie,
+        },
+        trie_store::{
+            in_memory::InMemoryTrieStore,
+            operations::{write, WriteResult},
+        },
+    };
+
+    type TestKey = [u8; 4];
+    type TestValue = Vec<u8>;
+
+    const TEST_KEYS: [TestKey; 4] = [[1, 2, 3, 4], [1, 2, 3, 0], [0, 0, 0, 1], [255, 0, 0, 0]];
+
+    fn create_store() -> (InMemoryEnvironment, InMemoryTrieStore, Blake2bHash) {
+        let env = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&env, None);
+        let (mut root_hash, root) = create_hashed_empty_trie::<TestKey, TestValue>().unwrap();
+
+        let mut txn = env.create_read_write_txn().unwrap();
+        store.put(&mut txn, &root_hash, &root).unwrap();
+        for key in TEST_KEYS.iter() {
+            let value = key.to_vec();
+            let write_result = write::<_, _, _, _, in_memory::Error>(
+                CorrelationId::new(),
+                &mut txn,
+                &store,
+                &root_hash,
+                key,
+                &value,
+            )
+            .unwrap();
+            match write_result {
+                WriteResult::Written(new_root_hash) => root_hash = new_root_hash,
+                _ => panic!("should write {:?}", key),
+            }
+        }
+        txn.commit().unwrap();
+
+        (env, store, root_hash)
+    }
+
+    fn create_proof(key: &TestKey) -> (TrieProof<TestKey, TestValue>, Blake2bHash) {
+        let (env, store, root_hash) = create_store();
+        let txn = env.create_read_txn().unwrap();
+        let proof = prove::<_, _, _, _, in_memory::Error>(&store, &txn, &root_hash, key)
+            .unwrap()
+            .expect("root should exist");
+        txn.commit().unwrap();
+        (proof, root_hash)
+    }
+
+    #[test]
+    fn should_verify_proof_of_present_key() {
+        for key in TEST_KEYS.iter() {
+            let (proof, root_hash) = create_proof(key);
+            assert_eq!(proof.verify(root_hash), Ok(Some(key.to_vec())));
+        }
+    }
+
+    #[test]
+    fn should_verify_proof_of_absent_key() {
+        // Diverges at the root node, at a deeper node, and at a leaf respectively.
+        for key in [[7, 0, 0, 0], [1, 2, 3, 9], [0, 0, 0, 2]].iter() {
+            let (proof, root_hash) = create_proof(key);
+            assert_eq!(proof.verify(root_hash), Ok(None));
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_tampered_proof() {
+        let key = TEST_KEYS[0];
+        let (proof, root_hash) = create_proof(&key);
+
+        let mut tries = proof.tries().to_vec();
+        match tries.last_mut() {
+            Some(Trie::Leaf { value, .. }) => *value = b"tampered".to_vec(),
+            _ => panic!("proof should end in a leaf"),
+        }
+        let tampered_position = tries.len() - 1;
+        let tampered_proof = TrieProof::new(key, tries);
+        assert_eq!(
+            tampered_proof.verify(root_hash),
+            Err(TrieProofError::BrokenLink(tampered_position))
+        );
+
+        let truncated_proof = TrieProof::new(key, proof.tries()[..1].to_vec());
+        assert_eq!(truncated_proof.verify(root_hash), Err(TrieProofError::WrongLength));
+
+        let wrong_root = Blake2bHash::new(b"wrong root");
+        assert_eq!(proof.verify(wrong_root), Err(TrieProofError::RootMismatch));
+    }
+
+    fn prove_from_tries(
+        tries: &[Trie<TestKey, TestValue>],
+        key: &TestKey,
+    ) -> Result<Option<TrieProof<TestKey, TestValue>>, TrieProveError<in_memory::Error>> {
+        let env = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&env, None);
+        let mut txn = env.create_read_write_txn().unwrap();
+        for trie in tries {
+            let hash = Blake2bHash::new(&trie.to_bytes().unwrap());
+            store.put(&mut txn, &hash, trie).unwrap();
+        }
+        txn.commit().unwrap();
+
+        let root_hash = Blake2bHash::new(&tries[0].to_bytes().unwrap());
+        let txn = env.create_read_txn().unwrap();
+        let result = prove::<_, _, _, _, in_memory::Error>(&store, &txn, &root_hash, key);
+        txn.commit().unwrap();
+        result
+    }
+
+    #[test]
+    fn should_fail_to_prove_on_dangling_pointer() {
+        let missing_leaf_hash = Blake2bHash::new(b"missing leaf");
+        let root = Trie::node(&[(1, Pointer::LeafPointer(missing_leaf_hash))]);
+        assert_eq!(
+            prove_from_tries(&[root], &[1, 2, 3, 4]),
+            Err(TrieProveError::DanglingPointer(missing_leaf_hash))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_prove_when_path_is_exhausted() {
+        // Five nodes deep, one more than the length of the key's path.
+        let leaf_pointer = Pointer::LeafPointer(Blake2bHash::new(b"unreachable leaf"));
+        let mut tries = vec![Trie::node(&[(0, leaf_pointer)])];
+        for _ in 0..4 {
+            let child_hash = Blake2bHash::new(&tries[0].to_bytes().unwrap());
+            tries.insert(0, Trie::node(&[(0, Pointer::NodePointer(child_hash))]));
+        }
+        assert_eq!(
+            prove_from_tries(&tries, &[0, 0, 0, 0]),
+            Err(TrieProveError::PathExhausted)
+        );
+    }
+}
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests;
 
-use std::{cmp, collections::VecDeque, mem, time::Instant};
+use std::{
+    cmp,
+    collections::{HashSet, VecDeque},
+    mem,
+    time::Instant,
+};
 
 use engine_shared::{
     logging::{log_duration, log_metric},
@@ -11,7 +16,7 @@ use types::bytesrepr::{self, FromBytes, ToBytes};
 
 use crate::{
     transaction_source::{Readable, Writable},
-    trie::{self, Parents, Pointer, Trie, RADIX},
+    trie::{self, operations::trie_hash, Parents, Pointer, Trie, RADIX},
     trie_store::TrieStore,
     GAUGE_METRIC_KEY,
 };
@@ -20,11 +25,14 @@ const TRIE_STORE_READ_DURATION: &str = "trie_store_read_duration";
 const TRIE_STORE_READ_GETS: &str = "trie_store_read_gets";
 const TRIE_STORE_SCAN_DURATION: &str = "trie_store_scan_duration";
 const TRIE_STORE_SCAN_GETS: &str = "trie_store_scan_gets";
+const TRIE_STORE_PREFETCH_DURATION: &str = "trie_store_prefetch_duration";
+const TRIE_STORE_PREFETCH_GETS: &str = "trie_store_prefetch_gets";
 const TRIE_STORE_WRITE_DURATION: &str = "trie_store_write_duration";
 const TRIE_STORE_WRITE_PUTS: &str = "trie_store_write_puts";
 const READ: &str = "read";
 const GET: &str = "get";
 const SCAN: &str = "scan";
+const PREFETCH: &str = "prefetch";
 const WRITE: &str = "write";
 const PUT: &str = "put";
 
@@ -199,6 +207,81 @@ where
     }
 }
 
+/// Reads the tries on the path to each of `keys` at a given root in a given store, so that they
+/// are held in any cache backing the store.
+///
+/// Returns the number of distinct tries read, including the root and any leaves, or zero if the
+/// root is not in the store. The walk for a given key stops where that key is shown to be absent.
+/// The store is not modified.
+pub fn prefetch<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    keys: &[K],
+) -> Result<usize, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let start = Instant::now();
+    let mut get_counter: i32 = 0;
+    let mut visited: HashSet<Blake2bHash> = HashSet::new();
+
+    for key in keys {
+        let path: Vec<u8> = key.to_bytes()?;
+        let mut depth: usize = 0;
+        let mut maybe_hash = Some(*root);
+
+        while let Some(hash) = maybe_hash.take() {
+            get_counter += 1;
+            let trie = match store.get(txn, &hash)? {
+                Some(trie) => trie,
+                None => break,
+            };
+            visited.insert(hash);
+
+            maybe_hash = match trie {
+                Trie::Leaf { .. } => None,
+                Trie::Node { pointer_block } => {
+                    let maybe_pointer = path
+                        .get(depth)
+                        .and_then(|index| pointer_block[usize::from(*index)]);
+                    depth += 1;
+                    maybe_pointer.map(Pointer::into_hash)
+                }
+                Trie::Extension { affix, pointer } => {
+                    if path[depth..].starts_with(&affix) {
+                        depth += affix.len();
+                        Some(pointer.into_hash())
+                    } else {
+                        None
+                    }
+                }
+            };
+        }
+    }
+
+    log_metric(
+        correlation_id,
+        TRIE_STORE_PREFETCH_GETS,
+        GET,
+        GAUGE_METRIC_KEY,
+        f64::from(get_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_PREFETCH_DURATION,
+        PREFETCH,
+        start.elapsed(),
+    );
+    Ok(visited.len())
+}
+
 struct TrieScan<K, V> {
     tip: Trie<K, V>,
     parents: Parents<K, V>,
@@ -381,10 +464,7 @@ where
     V: ToBytes + Clone,
 {
     let mut ret: Vec<(Blake2bHash, Trie<K, V>)> = Vec::new();
-    let mut tip_hash = {
-        let trie_bytes = tip.to_bytes()?;
-        Blake2bHash::new(&trie_bytes)
-    };
+    let mut tip_hash = trie_hash(&tip)?;
     ret.push((tip_hash, tip.to_owned()));
 
     for (index, parent) in parents.into_iter().rev() {
@@ -402,10 +482,7 @@ where
                     pointer_block[index.into()] = Some(pointer);
                     Trie::Node { pointer_block }
                 };
-                tip_hash = {
-                    let node_bytes = tip.to_bytes()?;
-                    Blake2bHash::new(&node_bytes)
-                };
+                tip_hash = trie_hash(&tip)?;
                 ret.push((tip_hash, tip.to_owned()))
             }
             Trie::Extension { affix, pointer } => {
@@ -413,10 +490,7 @@ where
                     let pointer = pointer.update(tip_hash);
                     Trie::Extension { affix, pointer }
                 };
-                tip_hash = {
-                    let extension_bytes = tip.to_bytes()?;
-                    Blake2bHash::new(&extension_bytes)
-                };
+                tip_hash = trie_hash(&tip)?;
                 ret.push((tip_hash, tip.to_owned()))
             }
         }
@@ -528,8 +602,7 @@ where
     // If the affix is non-empty, create an extension node and add it
     // to parents.
     if !affix.is_empty() {
-        let new_node_bytes = new_node.to_bytes()?;
-        let new_node_hash = Blake2bHash::new(&new_node_bytes);
+        let new_node_hash = trie_hash(&new_node)?;
         let new_extension = Trie::extension(affix.to_vec(), Pointer::NodePointer(new_node_hash));
         parents.push((child_index, new_extension));
     }
@@ -570,25 +643,27 @@ where
         parents_path.iter().chain(affix.iter()).cloned().collect();
     // Get the path that the new leaf and existing leaf share
     let shared_path = common_prefix(&new_leaf_path, &existing_extension_path);
-    // Create an affix for a possible parent extension above the new
-    // node.
-    let parent_extension_affix = shared_path[parents_path.len()..].to_vec();
-    // Create an affix for a possible child extension between the new
-    // node and the node that the existing extension pointed to.
-    let child_extension_affix = affix[parent_extension_affix.len() + 1..].to_vec();
+    // Split the affix into one for a possible parent extension above the
+    // new node, the index of the existing extension's pointer in the new
+    // node, and one for a possible child extension between the new node
+    // and the node that the existing extension pointed to.
+    let (parent_extension_affix, maybe_index, child_extension_affix) =
+        trie::split_affix(&affix, shared_path.len() - parents_path.len())
+            .expect("shared path should end within the existing extension's affix");
+    let index =
+        maybe_index.expect("new leaf path should diverge before the end of the existing affix");
     // Create a child extension (paired with its hash) if necessary
     let maybe_hashed_child_extension: Option<(Blake2bHash, Trie<K, V>)> =
         if child_extension_affix.is_empty() {
             None
         } else {
             let child_extension = Trie::extension(child_extension_affix.to_vec(), pointer);
-            let child_extension_bytes = child_extension.to_bytes()?;
-            let child_extension_hash = Blake2bHash::new(&child_extension_bytes);
+            let child_extension_hash = trie_hash(&child_extension)?;
             Some((child_extension_hash, child_extension))
         };
     // Assemble a new node.
     let new_node: Trie<K, V> = {
-        let index: usize = existing_extension_path[shared_path.len()].into();
+        let index: usize = index.into();
         let pointer = maybe_hashed_child_extension
             .to_owned()
             .map_or(pointer, |(hash, _)| Pointer::NodePointer(hash));
@@ -596,8 +671,7 @@ where
     };
     // Create a parent extension if necessary
     if !parent_extension_affix.is_empty() {
-        let new_node_bytes = new_node.to_bytes()?;
-        let new_node_hash = Blake2bHash::new(&new_node_bytes);
+        let new_node_hash = trie_hash(&new_node)?;
         let parent_extension = Trie::extension(
             parent_extension_affix.to_vec(),
             Pointer::NodePointer(new_node_hash),
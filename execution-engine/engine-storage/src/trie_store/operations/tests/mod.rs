@@ -1,4 +1,5 @@
 mod keys;
+mod prefetch;
 mod proptests;
 mod read;
 mod scan;
@@ -0,0 +1,130 @@
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie_store::operations::prefetch,
+};
+
+/// A key which diverges from every key in [`TEST_LEAVES`](super::TEST_LEAVES) at the root.
+const MISSING_KEY: TestKey = TestKey([1u8, 0, 0, 0, 0, 0, 0]);
+
+fn leaf_key(index: usize) -> TestKey {
+    *TEST_LEAVES[index].key().expect("should be a leaf")
+}
+
+/// Pairs of keys to prefetch from the 6-leaf trie and the number of distinct tries expected to be
+/// read in doing so.
+fn test_cases() -> Vec<(Vec<TestKey>, usize)> {
+    let all_keys: Vec<TestKey> = (0..TEST_LEAVES_LENGTH).map(leaf_key).collect();
+    vec![
+        (vec![], 0),
+        // Root, five nodes, an extension and the leaf.
+        (vec![leaf_key(0)], 8),
+        (vec![leaf_key(0), leaf_key(0)], 8),
+        // The two leaves share all of their parents.
+        (vec![leaf_key(0), leaf_key(1)], 9),
+        (vec![leaf_key(4)], 3),
+        (vec![MISSING_KEY], 1),
+        (vec![MISSING_KEY, leaf_key(4)], 3),
+        // Every trie is on the path to at least one leaf.
+        (all_keys, 13),
+    ]
+}
+
+fn check_prefetch<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    keys: &[TestKey],
+    expected_count: usize,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let txn: R::ReadTransaction = environment.create_read_txn()?;
+    let count = prefetch::<_, _, _, _, E>(correlation_id, &txn, store, root, keys)?;
+    txn.commit()?;
+    assert_eq!(count, expected_count, "prefetching {:?}", keys);
+    Ok(())
+}
+
+#[test]
+fn lmdb_prefetch_should_count_distinct_tries_read() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+    assert_eq!(tries.len(), 13);
+
+    for (keys, expected_count) in test_cases() {
+        check_prefetch::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &keys,
+            expected_count,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_prefetch_should_count_distinct_tries_read() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    assert_eq!(tries.len(), 13);
+
+    for (keys, expected_count) in test_cases() {
+        check_prefetch::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &keys,
+            expected_count,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_prefetch_from_missing_root_should_read_nothing() {
+    let correlation_id = CorrelationId::new();
+    let (_root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    check_prefetch::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &Blake2bHash::new(b"missing root"),
+        &[leaf_key(0)],
+        0,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_prefetch_should_not_modify_store() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let expected_contents: HashMap<Blake2bHash, TestTrie> = context.environment.dump(None).unwrap();
+
+    let all_keys: Vec<TestKey> = (0..TEST_LEAVES_LENGTH).map(leaf_key).collect();
+    check_prefetch::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        &all_keys,
+        tries.len(),
+    )
+    .unwrap();
+
+    assert_eq!(context.environment.dump(None).unwrap(), expected_contents);
+}
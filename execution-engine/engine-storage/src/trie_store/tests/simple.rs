@@ -1,12 +1,15 @@
+use std::collections::BTreeMap;
+
 use lmdb::DatabaseFlags;
 use tempfile::tempdir;
 
+use engine_shared::newtypes::Blake2bHash;
 use types::bytesrepr::{FromBytes, ToBytes};
 
 use super::TestData;
 use crate::{
     error::{self, in_memory},
-    store::StoreExt,
+    store::tests as store_tests,
     transaction_source::{
         in_memory::InMemoryEnvironment, lmdb::LmdbEnvironment, Transaction, TransactionSource,
     },
@@ -119,6 +122,97 @@ fn lmdb_put_get_succeeds() {
     tmp_dir.close().unwrap();
 }
 
+type TestItems = BTreeMap<Blake2bHash, Trie<Vec<u8>, Vec<u8>>>;
+
+// Splits the test data into items to be stored and keys which will be missing from the store.
+fn create_items_and_missing_keys() -> (TestItems, Vec<Blake2bHash>) {
+    let mut data = super::create_data();
+    let missing_keys = data.split_off(4).into_iter().map(|TestData(k, _)| k).collect();
+    let items = data.into_iter().map(|TestData(k, v)| (k, v)).collect();
+    (items, missing_keys)
+}
+
+#[test]
+fn in_memory_get_many_preserves_order() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    let (items, missing_keys) = create_items_and_missing_keys();
+
+    assert!(
+        store_tests::get_many_preserves_order_succeeds(&env, &store, items, missing_keys)
+            .expect("get_many_preserves_order failed")
+    );
+}
+
+#[test]
+fn lmdb_get_many_preserves_order() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+    let (items, missing_keys) = create_items_and_missing_keys();
+
+    assert!(
+        store_tests::get_many_preserves_order_succeeds(&env, &store, items, missing_keys)
+            .expect("get_many_preserves_order failed")
+    );
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn in_memory_get_raw_agrees_with_get() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    let items = super::create_data().into_iter().map(|TestData(k, v)| (k, v)).collect();
+
+    assert!(
+        store_tests::get_raw_agrees_with_get_succeeds(&env, &store, items)
+            .expect("get_raw_agrees_with_get failed")
+    );
+}
+
+#[test]
+fn lmdb_get_raw_agrees_with_get() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+    let items = super::create_data().into_iter().map(|TestData(k, v)| (k, v)).collect();
+
+    assert!(
+        store_tests::get_raw_agrees_with_get_succeeds(&env, &store, items)
+            .expect("get_raw_agrees_with_get failed")
+    );
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn in_memory_value_size_succeeds() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    let (items, missing_keys) = create_items_and_missing_keys();
+
+    assert!(
+        store_tests::value_size_succeeds(&env, &store, items, missing_keys)
+            .expect("value_size failed")
+    );
+}
+
+#[test]
+fn lmdb_value_size_succeeds() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+    let (items, missing_keys) = create_items_and_missing_keys();
+
+    assert!(
+        store_tests::value_size_succeeds(&env, &store, items, missing_keys)
+            .expect("value_size failed")
+    );
+
+    tmp_dir.close().unwrap();
+}
+
 #[test]
 fn in_memory_put_get_many_succeeds() {
     let env = InMemoryEnvironment::new();
@@ -160,6 +254,126 @@ fn lmdb_put_get_many_succeeds() {
     tmp_dir.close().unwrap();
 }
 
+fn put_delete_get_returns_none<'a, K, V, S, X, E>(
+    store: &S,
+    transaction_source: &'a X,
+    items: &[TestData<K, V>],
+) -> Result<Vec<Option<Trie<K, V>>>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    S: TrieStore<K, V>,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S::Error: From<X::Error>,
+    E: From<S::Error> + From<X::Error>,
+{
+    let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+    store.put_many(&mut txn, items.iter().map(Into::into))?;
+    for TestData(key, _) in items {
+        store.delete(&mut txn, key)?;
+        // Deleting a missing key is a no-op.
+        store.delete(&mut txn, key)?;
+    }
+    txn.commit()?;
+
+    let txn: X::ReadTransaction = transaction_source.create_read_txn()?;
+    let ret = store.get_many(&txn, items.iter().map(|TestData(k, _)| k))?;
+    txn.commit()?;
+    Ok(ret)
+}
+
+#[test]
+fn in_memory_put_delete_get_returns_none() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    let data = super::create_data();
+
+    let ret = put_delete_get_returns_none::<_, _, _, _, in_memory::Error>(&store, &env, &data)
+        .expect("put_delete_get failed");
+    assert!(ret.iter().all(Option::is_none));
+}
+
+#[test]
+fn lmdb_put_delete_get_returns_none() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+    let data = super::create_data();
+
+    let ret = put_delete_get_returns_none::<_, _, _, _, error::Error>(&store, &env, &data)
+        .expect("put_delete_get failed");
+    assert!(ret.iter().all(Option::is_none));
+
+    tmp_dir.close().unwrap();
+}
+
+fn uncommitted_delete_does_not_persist<'a, K, V, S, X, E>(
+    store: &S,
+    transaction_source: &'a X,
+    items: &[TestData<K, V>],
+) -> Result<Vec<Option<Trie<K, V>>>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    S: TrieStore<K, V>,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S::Error: From<X::Error>,
+    E: From<S::Error> + From<X::Error>,
+{
+    {
+        let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+        store.put_many(&mut txn, items.iter().map(Into::into))?;
+        txn.commit()?;
+    }
+    {
+        let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+        for TestData(key, _) in items {
+            store.delete(&mut txn, key)?;
+        }
+    }
+    {
+        let txn: X::ReadTransaction = transaction_source.create_read_txn()?;
+        let ret = store.get_many(&txn, items.iter().map(|TestData(k, _)| k))?;
+        txn.commit()?;
+        Ok(ret)
+    }
+}
+
+#[test]
+fn in_memory_uncommitted_delete_does_not_persist() {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    let data = super::create_data();
+
+    let expected: Vec<Option<Trie<Vec<u8>, Vec<u8>>>> =
+        data.iter().map(|TestData(_, v)| Some(v.clone())).collect();
+
+    assert_eq!(
+        expected,
+        uncommitted_delete_does_not_persist::<_, _, _, _, in_memory::Error>(&store, &env, &data)
+            .expect("uncommitted_delete failed")
+    );
+}
+
+#[test]
+fn lmdb_uncommitted_delete_does_not_persist() {
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+    let data = super::create_data();
+
+    let expected: Vec<Option<Trie<Vec<u8>, Vec<u8>>>> =
+        data.iter().map(|TestData(_, v)| Some(v.clone())).collect();
+
+    assert_eq!(
+        expected,
+        uncommitted_delete_does_not_persist::<_, _, _, _, error::Error>(&store, &env, &data)
+            .expect("uncommitted_delete failed")
+    );
+
+    tmp_dir.close().unwrap();
+}
+
 fn uncommitted_read_write_txn_does_not_persist<'a, K, V, S, X, E>(
     store: &S,
     transaction_source: &'a X,
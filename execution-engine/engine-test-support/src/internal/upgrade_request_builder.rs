@@ -54,6 +54,9 @@ impl UpgradeRequestBuilder {
         new_costs.set_max_stack_height(wasm_costs.max_stack_height);
         new_costs.set_mem(wasm_costs.mem);
         new_costs.set_memcpy(wasm_costs.memcpy);
+        new_costs.set_control_flow(wasm_costs.control_flow);
+        new_costs.set_bit(wasm_costs.bit);
+        new_costs.set_host_function_base(wasm_costs.host_function_base);
         self.new_costs = Some(new_costs);
         self
     }
@@ -0,0 +1,67 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs, U512};
+
+const CONTRACT_GET_ARG_OPT: &str = "get_arg_opt.wasm";
+const ARG_EXPECT_PRESENT: &str = "expect_present";
+const ARG_VALUE: &str = "value";
+const EXPECTED_VALUE: u64 = 42;
+
+/// Calls get_arg_opt contract and returns Ok(()) in case no error, or String which is the error
+/// message returned by the engine
+fn call_get_arg_opt(args: RuntimeArgs) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG_OPT, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_get_present_optional_arg() {
+    let args = runtime_args! {
+        ARG_EXPECT_PRESENT => true,
+        ARG_VALUE => U512::from(EXPECTED_VALUE),
+    };
+    call_get_arg_opt(args).expect("should get present optional arg");
+}
+
+#[ignore]
+#[test]
+fn should_get_absent_optional_arg() {
+    let args = runtime_args! { ARG_EXPECT_PRESENT => false };
+    call_get_arg_opt(args).expect("should get absent optional arg as None");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_optional_arg() {
+    let args = runtime_args! {
+        ARG_EXPECT_PRESENT => true,
+        ARG_VALUE => String::from("this is expected to be U512"),
+    };
+    let error_message = call_get_arg_opt(args).expect_err("should fail");
+    assert!(
+        error_message.contains(&format!("{:?}", ApiError::InvalidArgument)),
+        "error_message: {:?}",
+        error_message
+    );
+}
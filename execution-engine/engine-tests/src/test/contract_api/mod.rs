@@ -1,12 +1,14 @@
 mod account;
 mod create_purse;
 mod get_arg;
+mod get_arg_opt;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
+mod read_local;
 mod revert;
 mod subcall;
 mod transfer;
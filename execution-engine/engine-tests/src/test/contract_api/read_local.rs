@@ -0,0 +1,93 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs};
+
+const CONTRACT_READ_LOCAL_OR_DEFAULT: &str = "read_local_or_default.wasm";
+const CONTRACT_READ_LOCAL_TYPED: &str = "read_local_typed.wasm";
+const ARG_READ_WRONG_TYPE: &str = "read_wrong_type";
+
+#[ignore]
+#[test]
+fn should_read_local_or_default() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_LOCAL_OR_DEFAULT,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_revert_if_local_value_has_wrong_type() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_LOCAL_OR_DEFAULT,
+        runtime_args! { ARG_READ_WRONG_TYPE => true },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have error message");
+    assert!(
+        error_message.contains(&format!("{:?}", ApiError::Read)),
+        error_message
+    );
+}
+
+#[ignore]
+#[test]
+fn should_read_local_typed() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_LOCAL_TYPED,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_revert_if_local_typed_value_has_wrong_type() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_LOCAL_TYPED,
+        runtime_args! { ARG_READ_WRONG_TYPE => true },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have error message");
+    assert!(
+        error_message.contains(&format!("{:?}", ApiError::CLTypeMismatch)),
+        error_message
+    );
+}
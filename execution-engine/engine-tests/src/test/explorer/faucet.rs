@@ -9,6 +9,9 @@ const NEW_ACCOUNT_ADDR: AccountHash = AccountHash::new([99u8; 32]);
 
 const ARG_TARGET: &str = "target";
 const ARG_AMOUNT: &str = "amount";
+const ARG_MAX_TOTAL: &str = "max_total";
+const DEFAULT_AMOUNT: u64 = 10_000_000;
+const DEFAULT_MAX_TOTAL: u64 = 10_000_000_000;
 
 #[ignore]
 #[test]
@@ -42,35 +45,127 @@ fn should_get_funds_from_faucet() {
 
 #[ignore]
 #[test]
-fn should_fail_if_already_funded() {
-    let amount = U512::from(1000);
-    let exec_request_1 = ExecuteRequestBuilder::standard(
-        DEFAULT_ACCOUNT_ADDR,
-        FAUCET_CONTRACT,
-        runtime_args! { ARG_TARGET => NEW_ACCOUNT_ADDR, ARG_AMOUNT => amount },
-    )
-    .build();
-    let exec_request_2 = ExecuteRequestBuilder::standard(
+fn should_get_default_amount_from_faucet_if_amount_not_provided() {
+    let exec_request = ExecuteRequestBuilder::standard(
         DEFAULT_ACCOUNT_ADDR,
         FAUCET_CONTRACT,
-        runtime_args! { ARG_TARGET => NEW_ACCOUNT_ADDR, ARG_AMOUNT => amount },
+        runtime_args! { ARG_TARGET => NEW_ACCOUNT_ADDR },
     )
     .build();
 
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(NEW_ACCOUNT_ADDR)
+        .expect("should get account");
+
+    let account_balance = builder.get_purse_balance(account.main_purse());
+    assert_eq!(
+        account_balance,
+        U512::from(DEFAULT_AMOUNT),
+        "faucet should have created account with default amount"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fund_repeatedly_up_to_max_total() {
+    // Two requests for this amount take the total funded to exactly `DEFAULT_MAX_TOTAL`.
+    let amount = U512::from(DEFAULT_MAX_TOTAL / 2);
+    let make_exec_request = || {
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            FAUCET_CONTRACT,
+            runtime_args! { ARG_TARGET => NEW_ACCOUNT_ADDR, ARG_AMOUNT => amount },
+        )
+        .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder
+        .run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(make_exec_request())
+        .expect_success()
+        .commit()
+        .exec(make_exec_request())
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(NEW_ACCOUNT_ADDR)
+        .expect("should get account");
+    let account_balance = builder.get_purse_balance(account.main_purse());
+    assert_eq!(
+        account_balance,
+        amount * 2,
+        "faucet should have funded account twice"
+    );
+
+    // Third request would take the total above `DEFAULT_MAX_TOTAL`; should fail.
+    builder.exec(make_exec_request());
+
+    let error_msg = builder
+        .exec_error_message(2)
+        .expect("should have error message");
+    assert!(
+        error_msg.contains(&format!("{:?}", ApiError::User(2))),
+        error_msg
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fund_repeatedly_up_to_provided_max_total() {
+    let amount = U512::from(1000);
+    let max_total = U512::from(2500);
+    let make_exec_request = || {
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            FAUCET_CONTRACT,
+            runtime_args! {
+                ARG_TARGET => NEW_ACCOUNT_ADDR,
+                ARG_AMOUNT => amount,
+                ARG_MAX_TOTAL => max_total,
+            },
+        )
+        .build()
+    };
+
     let mut builder = InMemoryWasmTestBuilder::default();
 
     builder
         .run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST)
-        .exec(exec_request_1)
+        .exec(make_exec_request())
         .expect_success()
         .commit()
-        .exec(exec_request_2); // should fail
+        .exec(make_exec_request())
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(NEW_ACCOUNT_ADDR)
+        .expect("should get account");
+    let account_balance = builder.get_purse_balance(account.main_purse());
+    assert_eq!(
+        account_balance,
+        amount * 2,
+        "faucet should have funded account twice"
+    );
+
+    // Third request would take the total above `max_total`; should fail.
+    builder.exec(make_exec_request());
 
     let error_msg = builder
-        .exec_error_message(1)
+        .exec_error_message(2)
         .expect("should have error message");
     assert!(
-        error_msg.contains(&format!("{:?}", ApiError::User(1))),
+        error_msg.contains(&format!("{:?}", ApiError::User(2))),
         error_msg
     );
 }
@@ -10,6 +10,7 @@ use types::{account::AccountHash, runtime_args, ApiError, RuntimeArgs, U512};
 const FAUCET: &str = "faucet";
 const CALL_FAUCET: &str = "call_faucet";
 const NEW_ACCOUNT_ADDR: AccountHash = AccountHash::new([99u8; 32]);
+const DEFAULT_MAX_TOTAL: u64 = 10_000_000_000;
 
 fn get_builder() -> InMemoryWasmTestBuilder {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -78,7 +79,7 @@ fn should_get_funds_from_faucet_stored() {
 
 #[ignore]
 #[test]
-fn should_fail_if_already_funded() {
+fn should_fail_if_exceeding_max_total() {
     let mut builder = get_builder();
 
     let default_account = builder
@@ -92,7 +93,8 @@ fn should_fail_if_already_funded() {
         .into_hash()
         .expect("should be a hash");
 
-    let amount = U512::from(1000);
+    // Two requests for this amount take the total funded above `DEFAULT_MAX_TOTAL`.
+    let amount = U512::from(DEFAULT_MAX_TOTAL / 2 + 1);
 
     // call stored faucet
     let exec_request = {
@@ -137,7 +139,7 @@ fn should_fail_if_already_funded() {
 
     let error_message = utils::get_error_message(exec_response);
     assert!(
-        error_message.contains(&format!("{:?}", ApiError::User(1))),
-        "should have reverted with user error 1 (already funded)"
+        error_message.contains(&format!("{:?}", ApiError::User(2))),
+        "should have reverted with user error 2 (exceeds max funding)"
     );
 }
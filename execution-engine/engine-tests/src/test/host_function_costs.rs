@@ -0,0 +1,87 @@
+use engine_core::engine_state::{genesis::ExecConfig, run_genesis_request::RunGenesisRequest};
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PROTOCOL_VERSION, DEFAULT_WASM_COSTS,
+        MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::{runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_HOST_FUNCTION_CALLS: &str = "host_function_calls.wasm";
+const ARG_ITERATIONS: &str = "iterations";
+const ARG_CALL_HOST: &str = "call_host";
+const HOST_FUNCTION_BASE: u32 = 1_000;
+const ITERATIONS: u32 = 10;
+
+fn run_genesis_request(wasm_costs: WasmCosts) -> RunGenesisRequest {
+    let exec_config = ExecConfig::new(
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        DEFAULT_ACCOUNTS.clone(),
+        wasm_costs,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+/// Returns the cost of a deploy looping `iterations` times, less the cost of one not looping, under
+/// the given costs.  Each iteration makes a host function call if `call_host` is `true`.
+fn cost_of_iterations(wasm_costs: WasmCosts, iterations: u32, call_host: bool) -> Gas {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(wasm_costs));
+
+    let mut exec_cost = |iterations: u32| {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_HOST_FUNCTION_CALLS,
+            runtime_args! { ARG_ITERATIONS => iterations, ARG_CALL_HOST => call_host },
+        )
+        .build();
+        // Not committed, so that each deploy runs against the same state.
+        builder.exec(exec_request).expect_success();
+        builder.last_exec_gas_cost()
+    };
+
+    exec_cost(iterations) - exec_cost(0)
+}
+
+#[ignore]
+#[test]
+fn should_charge_host_function_base_cost_per_call() {
+    let uncharged_cost = cost_of_iterations(*DEFAULT_WASM_COSTS, ITERATIONS, true);
+
+    let wasm_costs = WasmCosts {
+        host_function_base: HOST_FUNCTION_BASE,
+        ..*DEFAULT_WASM_COSTS
+    };
+    let charged_cost = cost_of_iterations(wasm_costs, ITERATIONS, true);
+
+    assert_eq!(
+        charged_cost - uncharged_cost,
+        Gas::new(U512::from(HOST_FUNCTION_BASE * ITERATIONS))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_charge_host_function_base_cost_for_gas_metering() {
+    // Each iteration is metered by a call to the `gas` host function, but makes no other calls.
+    let uncharged_cost = cost_of_iterations(*DEFAULT_WASM_COSTS, ITERATIONS, false);
+    assert!(uncharged_cost > Gas::default());
+
+    let wasm_costs = WasmCosts {
+        host_function_base: HOST_FUNCTION_BASE,
+        ..*DEFAULT_WASM_COSTS
+    };
+    let charged_cost = cost_of_iterations(wasm_costs, ITERATIONS, false);
+
+    assert_eq!(charged_cost, uncharged_cost);
+}
@@ -35,6 +35,9 @@ fn get_upgraded_wasm_costs() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        control_flow: 1,
+        bit: 1,
+        host_function_base: 1,
     }
 }
 
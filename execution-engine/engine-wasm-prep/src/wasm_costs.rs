@@ -1,11 +1,47 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
 
 use pwasm_utils::rules::{InstructionType, Metering, Set};
 
 use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
 
-const NUM_FIELDS: usize = 10;
+const NUM_FIELDS: usize = 13;
+/// The number of fields which predate `control_flow`, `bit` and `host_function_base`.
+const NUM_LEGACY_FIELDS: usize = 10;
+const NUM_EXTENSION_FIELDS: usize = NUM_FIELDS - NUM_LEGACY_FIELDS;
 pub const WASM_COSTS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U32_SERIALIZED_LENGTH;
+/// The serialized length of the fields which predate `control_flow`, `bit` and
+/// `host_function_base`.
+pub const WASM_COSTS_LEGACY_SERIALIZED_LENGTH: usize = NUM_LEGACY_FIELDS * U32_SERIALIZED_LENGTH;
+
+/// The maximum number of 64kb pages addressable by a 32-bit Wasm memory.
+pub const MAX_WASM_MEM_PAGES: u32 = 65_536;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmCostsError {
+    /// `opcodes_div` is zero, which would cause a division by zero when costs are applied.
+    ZeroOpcodesDivisor,
+    /// `initial_mem` exceeds the number of pages addressable by a Wasm memory.
+    InitialMemTooLarge(u32),
+    /// `max_stack_height` is zero, which would cause every contract to fail.
+    ZeroMaxStackHeight,
+}
+
+impl Display for WasmCostsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            WasmCostsError::ZeroOpcodesDivisor => write!(f, "Opcodes divisor must be non-zero"),
+            WasmCostsError::InitialMemTooLarge(initial_mem) => write!(
+                f,
+                "Initial memory of {} pages exceeds maximum of {} pages",
+                initial_mem, MAX_WASM_MEM_PAGES
+            ),
+            WasmCostsError::ZeroMaxStackHeight => write!(f, "Max stack height must be non-zero"),
+        }
+    }
+}
 
 // Taken (partially) from parity-ethereum
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
@@ -33,9 +69,30 @@ pub struct WasmCosts {
     /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
     /// `opcodes_div`
     pub opcodes_div: u32,
+    /// Control flow (branch, call, return, etc.) operations cost.
+    pub control_flow: u32,
+    /// Bitwise (and, or, shift, etc.) operations cost.
+    pub bit: u32,
+    /// Base cost of each call to a host function, other than the `gas` metering function.  Zero
+    /// disables the charge, as is the case for costs predating this field.
+    pub host_function_base: u32,
 }
 
 impl WasmCosts {
+    /// Checks the invariants which must hold for these costs to be safely applied.
+    pub fn validate(&self) -> Result<(), WasmCostsError> {
+        if self.opcodes_div == 0 {
+            return Err(WasmCostsError::ZeroOpcodesDivisor);
+        }
+        if self.initial_mem > MAX_WASM_MEM_PAGES {
+            return Err(WasmCostsError::InitialMemTooLarge(self.initial_mem));
+        }
+        if self.max_stack_height == 0 {
+            return Err(WasmCostsError::ZeroMaxStackHeight);
+        }
+        Ok(())
+    }
+
     pub(crate) fn to_set(&self) -> Set {
         let meterings = {
             let mut tmp = BTreeMap::new();
@@ -43,17 +100,18 @@ impl WasmCosts {
             tmp.insert(InstructionType::Store, Metering::Fixed(self.mem));
             tmp.insert(InstructionType::Div, Metering::Fixed(self.div));
             tmp.insert(InstructionType::Mul, Metering::Fixed(self.mul));
+            tmp.insert(InstructionType::ControlFlow, Metering::Fixed(self.control_flow));
+            tmp.insert(InstructionType::Bit, Metering::Fixed(self.bit));
             tmp
         };
         Set::new(self.regular, meterings)
             .with_grow_cost(self.grow_mem)
             .with_forbidden_floats()
     }
-}
 
-impl ToBytes for WasmCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+    /// Serializes the fields which predate `control_flow`, `bit` and `host_function_base`.
+    pub fn legacy_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(WASM_COSTS_LEGACY_SERIALIZED_LENGTH);
         ret.append(&mut self.regular.to_bytes()?);
         ret.append(&mut self.div.to_bytes()?);
         ret.append(&mut self.mul.to_bytes()?);
@@ -67,13 +125,20 @@ impl ToBytes for WasmCosts {
         Ok(ret)
     }
 
-    fn serialized_length(&self) -> usize {
-        WASM_COSTS_SERIALIZED_LENGTH
+    /// Serializes `control_flow`, `bit` and `host_function_base`.
+    pub fn extension_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(NUM_EXTENSION_FIELDS * U32_SERIALIZED_LENGTH);
+        ret.append(&mut self.control_flow.to_bytes()?);
+        ret.append(&mut self.bit.to_bytes()?);
+        ret.append(&mut self.host_function_base.to_bytes()?);
+        Ok(ret)
     }
-}
 
-impl FromBytes for WasmCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    /// Deserializes the fields which predate `control_flow`, `bit` and `host_function_base`.
+    ///
+    /// The newer fields are set so that pricing is as it was before they existed: `control_flow`
+    /// and `bit` take the value of `regular`, and `host_function_base` is zero.
+    pub fn legacy_from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (regular, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
         let (div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
         let (mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
@@ -95,11 +160,48 @@ impl FromBytes for WasmCosts {
             max_stack_height,
             opcodes_mul,
             opcodes_div,
+            control_flow: regular,
+            bit: regular,
+            host_function_base: 0,
+        };
+        Ok((wasm_costs, rem))
+    }
+
+    /// Deserializes `control_flow`, `bit` and `host_function_base`, replacing those in `self`.
+    pub fn extension_from_bytes(self, bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (control_flow, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (bit, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (host_function_base, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let wasm_costs = WasmCosts {
+            control_flow,
+            bit,
+            host_function_base,
+            ..self
         };
         Ok((wasm_costs, rem))
     }
 }
 
+impl ToBytes for WasmCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.legacy_to_bytes()?);
+        ret.append(&mut self.extension_to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        WASM_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for WasmCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasm_costs, rem) = WasmCosts::legacy_from_bytes(bytes)?;
+        wasm_costs.extension_from_bytes(rem)
+    }
+}
+
 pub mod gens {
     use proptest::{num, prop_compose};
 
@@ -117,6 +219,9 @@ pub mod gens {
             max_stack_height in num::u32::ANY,
             opcodes_mul in num::u32::ANY,
             opcodes_div in num::u32::ANY,
+            control_flow in num::u32::ANY,
+            bit in num::u32::ANY,
+            host_function_base in num::u32::ANY,
         ) -> WasmCosts {
             WasmCosts {
                 regular,
@@ -129,6 +234,9 @@ pub mod gens {
                 max_stack_height,
                 opcodes_mul,
                 opcodes_div,
+                control_flow,
+                bit,
+                host_function_base,
             }
         }
     }
@@ -141,7 +249,9 @@ mod tests {
     use types::bytesrepr;
 
     use super::gens;
-    use crate::wasm_costs::WasmCosts;
+    use crate::wasm_costs::{
+        WasmCosts, WasmCostsError, MAX_WASM_MEM_PAGES, WASM_COSTS_SERIALIZED_LENGTH,
+    };
 
     fn wasm_costs_mock() -> WasmCosts {
         WasmCosts {
@@ -155,6 +265,9 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            control_flow: 2,
+            bit: 1,
+            host_function_base: 4,
         }
     }
 
@@ -170,6 +283,9 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            control_flow: 0,
+            bit: 0,
+            host_function_base: 0,
         }
     }
 
@@ -181,6 +297,68 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_serialize_control_flow_and_bit_costs() {
+        let wasm_costs = WasmCosts {
+            control_flow: 7,
+            bit: 11,
+            ..wasm_costs_mock()
+        };
+        let bytes = bytesrepr::serialize(wasm_costs).expect("should serialize");
+        assert_eq!(bytes.len(), WASM_COSTS_SERIALIZED_LENGTH);
+        let deserialized: WasmCosts = bytesrepr::deserialize(bytes).expect("should deserialize");
+        assert_eq!(deserialized.control_flow, 7);
+        assert_eq!(deserialized.bit, 11);
+        assert_eq!(deserialized, wasm_costs);
+    }
+
+    #[test]
+    fn should_serialize_host_function_base_cost() {
+        let wasm_costs = WasmCosts {
+            host_function_base: 13,
+            ..wasm_costs_mock()
+        };
+        let bytes = bytesrepr::serialize(wasm_costs).expect("should serialize");
+        assert_eq!(bytes.len(), WASM_COSTS_SERIALIZED_LENGTH);
+        let deserialized: WasmCosts = bytesrepr::deserialize(bytes).expect("should deserialize");
+        assert_eq!(deserialized.host_function_base, 13);
+        assert_eq!(deserialized, wasm_costs);
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(wasm_costs_mock().validate(), Ok(()));
+        assert_eq!(wasm_costs_free().validate(), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_zero_opcodes_divisor() {
+        let wasm_costs = WasmCosts {
+            opcodes_div: 0,
+            ..wasm_costs_mock()
+        };
+        assert_eq!(wasm_costs.validate(), Err(WasmCostsError::ZeroOpcodesDivisor));
+    }
+
+    #[test]
+    fn should_reject_too_large_initial_mem() {
+        let initial_mem = MAX_WASM_MEM_PAGES + 1;
+        let wasm_costs = WasmCosts {
+            initial_mem,
+            ..wasm_costs_mock()
+        };
+        assert_eq!(wasm_costs.validate(), Err(WasmCostsError::InitialMemTooLarge(initial_mem)));
+    }
+
+    #[test]
+    fn should_reject_zero_max_stack_height() {
+        let wasm_costs = WasmCosts {
+            max_stack_height: 0,
+            ..wasm_costs_mock()
+        };
+        assert_eq!(wasm_costs.validate(), Err(WasmCostsError::ZeroMaxStackHeight));
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
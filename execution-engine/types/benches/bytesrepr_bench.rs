@@ -63,6 +63,17 @@ fn deserialize_vector_of_u8(b: &mut Bencher) {
     b.iter(|| Vec::<u8>::from_bytes(&data))
 }
 
+#[bench]
+fn deserialize_vector_of_u8_borrowed(b: &mut Bencher) {
+    let data: Vec<u8> = prepare_vector(BATCH)
+        .into_iter()
+        .map(|value| value as u8)
+        .collect::<Vec<_>>()
+        .to_bytes()
+        .unwrap();
+    b.iter(|| bytesrepr::bytes_from_slice(&data))
+}
+
 #[bench]
 fn serialize_u8(b: &mut Bencher) {
     b.iter(|| ToBytes::to_bytes(black_box(&129u8)));
@@ -259,6 +270,31 @@ fn serialize_key_uref(b: &mut Bencher) {
     b.iter(|| ToBytes::to_bytes(black_box(&uref)))
 }
 
+#[bench]
+fn serialize_many_keys(b: &mut Bencher) {
+    let keys: Vec<Key> = (0..10_000)
+        .map(|index| Key::URef(URef::new([index as u8; 32], AccessRights::ADD_WRITE)))
+        .collect();
+    b.iter(|| {
+        for key in &keys {
+            black_box(bytesrepr::serialize(*key).unwrap());
+        }
+    })
+}
+
+#[bench]
+fn serialize_many_keys_reusing_buffer(b: &mut Bencher) {
+    let keys: Vec<Key> = (0..10_000)
+        .map(|index| Key::URef(URef::new([index as u8; 32], AccessRights::ADD_WRITE)))
+        .collect();
+    let mut buffer = Vec::new();
+    b.iter(|| {
+        for key in &keys {
+            black_box(bytesrepr::serialize_reuse(key, &mut buffer).unwrap());
+        }
+    })
+}
+
 #[bench]
 fn deserialize_key_uref(b: &mut Bencher) {
     let uref = Key::URef(URef::new([0u8; 32], AccessRights::ADD_WRITE));
@@ -507,3 +543,26 @@ fn deserialize_u512(b: &mut Bencher) {
 
     b.iter(|| U512::from_bytes(black_box(&num_u512_bytes)))
 }
+
+#[bench]
+fn serialize_vector_of_u8_into_buffer(b: &mut Bencher) {
+    // 0, 1, ... 254, 255, 0, 1, ...
+    let data: Vec<u8> = prepare_vector(BATCH)
+        .into_iter()
+        .map(|value| value as u8)
+        .collect::<Vec<_>>();
+    let mut buffer = Vec::with_capacity(data.serialized_length());
+    b.iter(|| {
+        buffer.clear();
+        data.to_bytes_into(&mut buffer)
+    });
+}
+
+#[bench]
+fn serialize_vector_of_bool(b: &mut Bencher) {
+    let data: Vec<bool> = prepare_vector(BATCH)
+        .into_iter()
+        .map(|value| value % 2 == 0)
+        .collect::<Vec<_>>();
+    b.iter(|| data.to_bytes());
+}
@@ -0,0 +1,28 @@
+#![feature(test)]
+
+extern crate test;
+
+use test::{black_box, Bencher};
+
+use casperlabs_types::{AccessRights, URef};
+
+fn prepare_uref() -> URef {
+    URef::new([0xab; 32], AccessRights::READ_ADD_WRITE)
+}
+
+#[bench]
+fn format_uref_as_string(b: &mut Bencher) {
+    let uref = black_box(prepare_uref());
+    b.iter(|| uref.as_string());
+}
+
+#[bench]
+fn format_uref_into_reused_buffer(b: &mut Bencher) {
+    let uref = black_box(prepare_uref());
+    let mut buffer = String::with_capacity(128);
+    b.iter(|| {
+        buffer.clear();
+        uref.write_formatted(&mut buffer).unwrap();
+        black_box(buffer.len())
+    });
+}
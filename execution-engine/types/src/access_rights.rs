@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 use bitflags::bitflags;
 
@@ -7,6 +8,18 @@ use crate::bytesrepr;
 /// The number of bytes in a serialized [`AccessRights`].
 pub const ACCESS_RIGHTS_SERIALIZED_LENGTH: usize = 1;
 
+/// The maximum number of octal digits accepted by [`AccessRights::from_octal_str`].
+const MAX_OCTAL_DIGITS: usize = 3;
+
+/// Error while parsing [`AccessRights`] from an octal string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AccessRightsParseError {
+    /// The string isn't between one and three octal digits.
+    InvalidFormat,
+    /// The value doesn't correspond to valid [`AccessRights`]; contains the value.
+    InvalidValue(u16),
+}
+
 bitflags! {
     /// A struct which behaves like a set of bitflags to define access rights associated with a
     /// [`URef`](crate::URef).
@@ -31,6 +44,9 @@ bitflags! {
     }
 }
 
+/// The individual base flags, in the order yielded by [`AccessRights::iter_flags`].
+static BASE_FLAGS: [AccessRights; 3] = [AccessRights::READ, AccessRights::WRITE, AccessRights::ADD];
+
 impl Default for AccessRights {
     fn default() -> Self {
         AccessRights::NONE
@@ -38,6 +54,26 @@ impl Default for AccessRights {
 }
 
 impl AccessRights {
+    /// Parses between one and three octal digits, as written by
+    /// [`URef::as_string`](crate::URef::as_string), into `AccessRights`.
+    ///
+    /// Each digit's bits map directly onto the flags, so e.g. `"001"` is `READ` and `"007"` is
+    /// `READ_ADD_WRITE`. Values with bits set outside `READ_ADD_WRITE` are rejected.
+    pub fn from_octal_str(input: &str) -> Result<AccessRights, AccessRightsParseError> {
+        if input.is_empty()
+            || input.len() > MAX_OCTAL_DIGITS
+            || !input.bytes().all(|byte| (b'0'..=b'7').contains(&byte))
+        {
+            return Err(AccessRightsParseError::InvalidFormat);
+        }
+        let value =
+            u16::from_str_radix(input, 8).map_err(|_| AccessRightsParseError::InvalidFormat)?;
+        u8::try_from(value)
+            .ok()
+            .and_then(AccessRights::from_bits)
+            .ok_or(AccessRightsParseError::InvalidValue(value))
+    }
+
     /// Returns `true` if the `READ` flag is set.
     pub fn is_readable(self) -> bool {
         self & AccessRights::READ == AccessRights::READ
@@ -57,6 +93,36 @@ impl AccessRights {
     pub fn is_none(self) -> bool {
         self == AccessRights::NONE
     }
+
+    /// Returns an iterator over each of the base flags `READ`, `WRITE` and `ADD` which is set.
+    pub fn iter_flags(self) -> impl Iterator<Item = AccessRights> {
+        BASE_FLAGS
+            .iter()
+            .copied()
+            .filter(move |flag| self.contains(*flag))
+    }
+
+    /// Returns the number of base flags which are set.
+    pub fn count(self) -> u32 {
+        (self & AccessRights::READ_ADD_WRITE).bits.count_ones()
+    }
+
+    /// Returns the flags which are set in both `self` and `other`.
+    ///
+    /// This can be used to narrow a grant of access rights down to those requested.
+    pub fn intersection(self, other: AccessRights) -> AccessRights {
+        self & other
+    }
+
+    /// Returns the flags which are set in either `self` or `other`.
+    pub fn union(self, other: AccessRights) -> AccessRights {
+        self | other
+    }
+
+    /// Returns `true` if every flag set in `required` is also set in `self`.
+    pub fn contains_all(self, required: AccessRights) -> bool {
+        self.contains(required)
+    }
 }
 
 impl core::fmt::Display for AccessRights {
@@ -97,8 +163,64 @@ impl bytesrepr::FromBytes for AccessRights {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use super::*;
 
+    #[test]
+    fn should_parse_octal_str() {
+        assert_eq!(AccessRights::from_octal_str("001"), Ok(AccessRights::READ));
+        assert_eq!(AccessRights::from_octal_str("000"), Ok(AccessRights::NONE));
+        assert_eq!(AccessRights::from_octal_str("5"), Ok(AccessRights::READ_ADD));
+        assert_eq!(AccessRights::from_octal_str("007"), Ok(AccessRights::READ_ADD_WRITE));
+        for bits in 0..=AccessRights::READ_ADD_WRITE.bits() {
+            let rights = AccessRights::from_bits(bits).unwrap();
+            assert_eq!(AccessRights::from_octal_str(&format!("{:03o}", bits)), Ok(rights));
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_octal_str() {
+        let invalid_format = Err(AccessRightsParseError::InvalidFormat);
+        assert_eq!(AccessRights::from_octal_str("008"), invalid_format);
+        assert_eq!(AccessRights::from_octal_str(""), invalid_format);
+        assert_eq!(AccessRights::from_octal_str("0001"), invalid_format);
+        assert_eq!(AccessRights::from_octal_str("-1"), invalid_format);
+        assert_eq!(
+            AccessRights::from_octal_str("010"),
+            Err(AccessRightsParseError::InvalidValue(8))
+        );
+        assert_eq!(
+            AccessRights::from_octal_str("777"),
+            Err(AccessRightsParseError::InvalidValue(511))
+        );
+    }
+
+    #[test]
+    fn should_narrow_by_intersection() {
+        let granted = AccessRights::READ_ADD_WRITE;
+        assert_eq!(granted.intersection(AccessRights::READ), AccessRights::READ);
+        assert_eq!(granted.intersection(AccessRights::READ_ADD), AccessRights::READ_ADD);
+        assert_eq!(AccessRights::READ_ADD.intersection(AccessRights::ADD_WRITE), AccessRights::ADD);
+        assert_eq!(AccessRights::READ.intersection(AccessRights::WRITE), AccessRights::NONE);
+    }
+
+    #[test]
+    fn should_combine_by_union() {
+        assert_eq!(AccessRights::READ.union(AccessRights::WRITE), AccessRights::READ_WRITE);
+        assert_eq!(AccessRights::READ_ADD.union(AccessRights::NONE), AccessRights::READ_ADD);
+        assert_eq!(AccessRights::ADD_WRITE.union(AccessRights::READ), AccessRights::READ_ADD_WRITE);
+    }
+
+    #[test]
+    fn should_check_contains_all() {
+        assert!(AccessRights::READ_ADD_WRITE.contains_all(AccessRights::READ_WRITE));
+        assert!(AccessRights::READ.contains_all(AccessRights::READ));
+        assert!(AccessRights::READ.contains_all(AccessRights::NONE));
+        assert!(!AccessRights::READ.contains_all(AccessRights::READ_ADD));
+        assert!(!AccessRights::NONE.contains_all(AccessRights::WRITE));
+    }
+
     fn test_readable(right: AccessRights, is_true: bool) {
         assert_eq!(right.is_readable(), is_true)
     }
@@ -143,4 +265,35 @@ mod tests {
         test_addable(AccessRights::WRITE, false);
         test_addable(AccessRights::READ_ADD_WRITE, true);
     }
+
+    #[test]
+    fn test_iter_flags() {
+        let check = |rights: AccessRights, expected: &[AccessRights]| {
+            let flags: Vec<AccessRights> = rights.iter_flags().collect();
+            assert_eq!(flags, expected);
+            assert_eq!(rights.count() as usize, expected.len());
+        };
+        check(AccessRights::NONE, &[]);
+        check(AccessRights::READ, &[AccessRights::READ]);
+        check(AccessRights::WRITE, &[AccessRights::WRITE]);
+        check(AccessRights::ADD, &[AccessRights::ADD]);
+        check(AccessRights::READ_ADD, &[AccessRights::READ, AccessRights::ADD]);
+        check(AccessRights::READ_WRITE, &[AccessRights::READ, AccessRights::WRITE]);
+        check(AccessRights::ADD_WRITE, &[AccessRights::WRITE, AccessRights::ADD]);
+        check(
+            AccessRights::READ_ADD_WRITE,
+            &[AccessRights::READ, AccessRights::WRITE, AccessRights::ADD],
+        );
+    }
+
+    #[test]
+    fn iter_flags_should_recombine_to_original() {
+        for bits in 0..=AccessRights::READ_ADD_WRITE.bits() {
+            let rights = AccessRights::from_bits(bits).unwrap();
+            let recombined = rights
+                .iter_flags()
+                .fold(AccessRights::NONE, |acc, flag| acc | flag);
+            assert_eq!(rights, recombined);
+        }
+    }
 }
@@ -1,6 +1,10 @@
 //! Contains types and constants associated with user accounts.
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::{
     convert::TryFrom,
     fmt::{Debug, Display, Formatter},
@@ -10,7 +14,7 @@ use failure::Fail;
 
 use crate::{
     bytesrepr::{Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    CLType, CLTyped,
+    ApiError, CLType, CLTyped, Key,
 };
 
 // This error type is not intended to be used by third party crates.
@@ -118,6 +122,58 @@ impl Weight {
     pub fn value(self) -> u8 {
         self.0
     }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    pub fn checked_add(self, other: Weight) -> Option<Weight> {
+        self.0.checked_add(other.0).map(Weight::new)
+    }
+}
+
+/// Returns the sum of the given [`Weight`]s as a `u32`.
+///
+/// As each `Weight` is at most `u8::max_value()`, this cannot overflow for any realistic number of
+/// weights, and in particular not for [`MAX_ASSOCIATED_KEYS`] of them.
+pub fn total_weight(weights: impl IntoIterator<Item = Weight>) -> u32 {
+    weights
+        .into_iter()
+        .map(|weight| u32::from(weight.value()))
+        .sum()
+}
+
+/// A map of [`AccountHash`]s to [`Weight`]s, used to check whether a set of signers carries
+/// enough weight to meet a threshold.
+#[derive(Default, PartialEq, Eq, Clone, Debug)]
+pub struct WeightedKeys(BTreeMap<AccountHash, Weight>);
+
+impl WeightedKeys {
+    /// Constructs a `WeightedKeys` from `keys`.
+    ///
+    /// Returns [`AddKeyFailure::MaxKeysLimit`] if there are more than [`MAX_ASSOCIATED_KEYS`]
+    /// entries.
+    pub fn new(keys: BTreeMap<AccountHash, Weight>) -> Result<WeightedKeys, AddKeyFailure> {
+        if keys.len() > MAX_ASSOCIATED_KEYS {
+            return Err(AddKeyFailure::MaxKeysLimit);
+        }
+        Ok(WeightedKeys(keys))
+    }
+
+    /// Returns the sum of the weights of `signers` as a `u32`.
+    ///
+    /// Signers not in `self` contribute no weight, and a signer appearing more than once is only
+    /// counted once.
+    pub fn total_weight_of(&self, signers: &[AccountHash]) -> u32 {
+        let distinct_signers: BTreeSet<&AccountHash> = signers.iter().collect();
+        total_weight(
+            distinct_signers
+                .into_iter()
+                .filter_map(|signer| self.0.get(signer).copied()),
+        )
+    }
+
+    /// Returns `true` if the total weight of `signers` is at least `threshold`.
+    pub fn can_perform(&self, signers: &[AccountHash], threshold: Weight) -> bool {
+        self.total_weight_of(signers) >= u32::from(threshold.value())
+    }
 }
 
 impl ToBytes for Weight {
@@ -194,6 +250,18 @@ impl TryFrom<&alloc::vec::Vec<u8>> for AccountHash {
     }
 }
 
+impl TryFrom<Key> for AccountHash {
+    type Error = ApiError;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        if let Key::Account(account_hash) = key {
+            Ok(account_hash)
+        } else {
+            Err(ApiError::UnexpectedKeyVariant)
+        }
+    }
+}
+
 impl Display for AccountHash {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", base16::encode_lower(&self.0))
@@ -208,7 +276,7 @@ impl Debug for AccountHash {
 
 impl CLTyped for AccountHash {
     fn cl_type() -> CLType {
-        CLType::FixedList(Box::new(CLType::U8), 32)
+        CLType::ByteArray(32)
     }
 }
 
@@ -340,6 +408,7 @@ mod tests {
     use std::{convert::TryFrom, vec::Vec};
 
     use super::*;
+    use crate::{AccessRights, URef};
 
     #[test]
     fn account_hash_from_slice() {
@@ -360,6 +429,77 @@ mod tests {
             AccountHash::try_from(&[0u8; 33][..]).expect_err("should not create account hash");
     }
 
+    #[test]
+    fn account_hash_from_key() {
+        let account_hash = AccountHash::new([3; 32]);
+        assert_eq!(AccountHash::try_from(Key::Account(account_hash)), Ok(account_hash));
+        assert_eq!(AccountHash::try_from(Key::Hash([3; 32])), Err(ApiError::UnexpectedKeyVariant));
+        let uref = URef::new([3; 32], AccessRights::READ);
+        assert_eq!(AccountHash::try_from(Key::URef(uref)), Err(ApiError::UnexpectedKeyVariant));
+    }
+
+    #[test]
+    fn weight_checked_add() {
+        assert_eq!(Weight::new(254).checked_add(Weight::new(1)), Some(Weight::new(255)));
+        assert_eq!(Weight::new(255).checked_add(Weight::new(1)), None);
+    }
+
+    #[test]
+    fn total_weight_of_max_associated_keys() {
+        let weights = vec![Weight::new(u8::max_value()); MAX_ASSOCIATED_KEYS];
+        assert_eq!(total_weight(weights), 2550);
+        assert_eq!(total_weight(vec![Weight::new(255), Weight::new(1)]), 256);
+        assert_eq!(total_weight(Vec::new()), 0);
+    }
+
+    fn weighted_keys() -> WeightedKeys {
+        let keys = vec![
+            (AccountHash::new([1; 32]), Weight::new(1)),
+            (AccountHash::new([2; 32]), Weight::new(2)),
+            (AccountHash::new([3; 32]), Weight::new(255)),
+        ];
+        WeightedKeys::new(keys.into_iter().collect()).unwrap()
+    }
+
+    #[test]
+    fn weighted_keys_should_meet_and_fail_threshold() {
+        let weighted_keys = weighted_keys();
+        let signers = [AccountHash::new([1; 32]), AccountHash::new([2; 32])];
+        assert_eq!(weighted_keys.total_weight_of(&signers), 3);
+        assert!(weighted_keys.can_perform(&signers, Weight::new(3)));
+        assert!(!weighted_keys.can_perform(&signers, Weight::new(4)));
+
+        let all_signers = [signers[0], signers[1], AccountHash::new([3; 32])];
+        assert_eq!(weighted_keys.total_weight_of(&all_signers), 258);
+        assert!(weighted_keys.can_perform(&all_signers, Weight::new(255)));
+    }
+
+    #[test]
+    fn weighted_keys_should_ignore_unknown_and_repeated_signers() {
+        let weighted_keys = weighted_keys();
+        let unknown = AccountHash::new([4; 32]);
+        assert_eq!(weighted_keys.total_weight_of(&[unknown]), 0);
+        assert!(!weighted_keys.can_perform(&[unknown], Weight::new(1)));
+        assert!(weighted_keys.can_perform(&[unknown], Weight::new(0)));
+
+        let known = AccountHash::new([2; 32]);
+        assert_eq!(weighted_keys.total_weight_of(&[unknown, known, known]), 2);
+    }
+
+    #[test]
+    fn weighted_keys_should_enforce_max_associated_keys() {
+        let keys = |count: u8| {
+            (0..count)
+                .map(|i| (AccountHash::new([i; 32]), Weight::new(1)))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert!(WeightedKeys::new(keys(MAX_ASSOCIATED_KEYS as u8)).is_ok());
+        assert_eq!(
+            WeightedKeys::new(keys(MAX_ASSOCIATED_KEYS as u8 + 1)),
+            Err(AddKeyFailure::MaxKeysLimit)
+        );
+    }
+
     #[test]
     fn try_from_i32_for_set_threshold_failure() {
         let max_valid_value_for_variant = SetThresholdFailure::InsufficientTotalWeight as i32;
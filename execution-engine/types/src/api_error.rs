@@ -1,6 +1,7 @@
 //! Contains [`ApiError`] and associated helper functions.
 
 use core::{
+    convert::TryFrom,
     fmt::{self, Debug, Formatter},
     u16, u8,
 };
@@ -182,6 +183,12 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 34 => HostBufferFull
 /// # );
+/// # show_and_check!(
+/// 35 => AllocLayout
+/// # );
+/// # show_and_check!(
+/// 36 => InvalidActionType
+/// # );
 /// // Contract header errors:
 /// use casperlabs_types::contracts::Error as ContractHeaderError;
 /// # show_and_check!(
@@ -426,6 +433,9 @@ pub enum ApiError {
     HostBufferFull,
     /// Could not lay out an array in memory
     AllocLayout,
+    /// An integer doesn't map to a valid [`ActionType`](crate::account::ActionType) or to a
+    /// variant of one of the associated key management failure types.
+    InvalidActionType,
     /// Contract header errors.
     ContractHeader(u8),
     /// Error specific to Mint contract.
@@ -437,6 +447,17 @@ pub enum ApiError {
     User(u16),
 }
 
+impl ApiError {
+    /// Returns the [`mint::Error`] represented by this value if it is an `ApiError::Mint` holding
+    /// a valid mint error code, otherwise returns `None`.
+    pub fn as_typed_mint(&self) -> Option<mint::Error> {
+        match self {
+            ApiError::Mint(value) => mint::Error::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
 impl From<bytesrepr::Error> for ApiError {
     fn from(error: bytesrepr::Error) -> Self {
         match error {
@@ -508,7 +529,7 @@ impl From<contracts::Error> for ApiError {
 #[doc(hidden)]
 impl From<TryFromIntError> for ApiError {
     fn from(_error: TryFromIntError) -> Self {
-        ApiError::Unhandled
+        ApiError::InvalidActionType
     }
 }
 
@@ -568,6 +589,7 @@ impl From<ApiError> for u32 {
             ApiError::HostBufferEmpty => 33,
             ApiError::HostBufferFull => 34,
             ApiError::AllocLayout => 35,
+            ApiError::InvalidActionType => 36,
             ApiError::ContractHeader(value) => HEADER_ERROR_OFFSET + u32::from(value),
             ApiError::Mint(value) => MINT_ERROR_OFFSET + u32::from(value),
             ApiError::ProofOfStake(value) => POS_ERROR_OFFSET + u32::from(value),
@@ -614,6 +636,7 @@ impl From<u32> for ApiError {
             33 => ApiError::HostBufferEmpty,
             34 => ApiError::HostBufferFull,
             35 => ApiError::AllocLayout,
+            36 => ApiError::InvalidActionType,
             USER_ERROR_MIN..=USER_ERROR_MAX => ApiError::User(value as u16),
             POS_ERROR_MIN..=POS_ERROR_MAX => ApiError::ProofOfStake(value as u8),
             MINT_ERROR_MIN..=MINT_ERROR_MAX => ApiError::Mint(value as u8),
@@ -663,6 +686,7 @@ impl Debug for ApiError {
             ApiError::HostBufferEmpty => write!(f, "ApiError::HostBufferEmpty")?,
             ApiError::HostBufferFull => write!(f, "ApiError::HostBufferFull")?,
             ApiError::AllocLayout => write!(f, "ApiError::AllocLayout")?,
+            ApiError::InvalidActionType => write!(f, "ApiError::InvalidActionType")?,
             ApiError::ContractHeader(value) => write!(f, "ApiError::ContractHeader({})", value)?,
             ApiError::Mint(value) => write!(f, "ApiError::Mint({})", value)?,
             ApiError::ProofOfStake(value) => write!(f, "ApiError::ProofOfStake({})", value)?,
@@ -772,6 +796,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_convert_to_typed_mint_error() {
+        assert_eq!(
+            ApiError::from(mint::Error::InsufficientFunds).as_typed_mint(),
+            Some(mint::Error::InsufficientFunds)
+        );
+        assert_eq!(
+            ApiError::from(mint::Error::PurseNotFound).as_typed_mint(),
+            Some(mint::Error::PurseNotFound)
+        );
+        assert_eq!(ApiError::Mint(u8::MAX).as_typed_mint(), None);
+        assert_eq!(ApiError::ProofOfStake(0).as_typed_mint(), None);
+        assert_eq!(ApiError::User(0).as_typed_mint(), None);
+    }
+
     #[test]
     fn error_edge_cases() {
         assert_eq!(Err(ApiError::Unhandled), result_from(i32::MAX));
@@ -821,6 +860,7 @@ mod tests {
         round_trip(Err(ApiError::HostBufferEmpty));
         round_trip(Err(ApiError::HostBufferFull));
         round_trip(Err(ApiError::AllocLayout));
+        round_trip(Err(ApiError::InvalidActionType));
         round_trip(Err(ApiError::ContractHeader(0)));
         round_trip(Err(ApiError::ContractHeader(u8::MAX)));
         round_trip(Err(ApiError::Mint(0)));
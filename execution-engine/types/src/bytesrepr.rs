@@ -12,7 +12,12 @@ use alloc::{
     string::String,
     vec::Vec,
 };
-use core::mem::{self, MaybeUninit};
+use core::{
+    cmp,
+    convert::TryFrom,
+    mem::{self, MaybeUninit},
+    str,
+};
 #[cfg(feature = "no-unstable-features")]
 use core::ptr::NonNull;
 
@@ -22,6 +27,10 @@ use failure::Fail;
 pub const UNIT_SERIALIZED_LENGTH: usize = 0;
 /// The number of bytes in a serialized `bool`.
 pub const BOOL_SERIALIZED_LENGTH: usize = 1;
+/// The number of bytes in a serialized `i8`.
+pub const I8_SERIALIZED_LENGTH: usize = mem::size_of::<i8>();
+/// The number of bytes in a serialized `i16`.
+pub const I16_SERIALIZED_LENGTH: usize = mem::size_of::<i16>();
 /// The number of bytes in a serialized `i32`.
 pub const I32_SERIALIZED_LENGTH: usize = mem::size_of::<i32>();
 /// The number of bytes in a serialized `i64`.
@@ -34,6 +43,12 @@ pub const U16_SERIALIZED_LENGTH: usize = mem::size_of::<u16>();
 pub const U32_SERIALIZED_LENGTH: usize = mem::size_of::<u32>();
 /// The number of bytes in a serialized `u64`.
 pub const U64_SERIALIZED_LENGTH: usize = mem::size_of::<u64>();
+/// The number of bytes in a serialized `i128`.
+pub const I128_SERIALIZED_LENGTH: usize = mem::size_of::<i128>();
+/// The number of bytes in a serialized `f32`.
+pub const F32_SERIALIZED_LENGTH: usize = mem::size_of::<f32>();
+/// The number of bytes in a serialized `f64`.
+pub const F64_SERIALIZED_LENGTH: usize = mem::size_of::<f64>();
 /// The number of bytes in a serialized [`U128`](crate::U128).
 pub const U128_SERIALIZED_LENGTH: usize = mem::size_of::<u128>();
 /// The number of bytes in a serialized [`U256`](crate::U256).
@@ -52,10 +67,24 @@ pub trait ToBytes {
     {
         self.to_bytes()
     }
+    /// Serializes `&self` and appends the resulting bytes to `buffer`.
+    ///
+    /// The default implementation delegates to `to_bytes()`.  Implementations for types composed
+    /// of other serializable values override this so that a whole structure can be written into
+    /// a single buffer without allocating an intermediate `Vec<u8>` per element.
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.append(&mut self.to_bytes()?);
+        Ok(())
+    }
     /// Returns the length of the `Vec<u8>` which would be returned from a successful call to
     /// `to_bytes()` or `into_bytes()`.  The data is not actually serialized, so this call is
     /// relatively cheap.
     fn serialized_length(&self) -> usize;
+    /// Returns the value of `serialized_length()` as a `u32`, or `Error::OutOfMemory` if it would
+    /// exceed `u32::max_value()`, in which case serializing `self` would fail with the same error.
+    fn try_serialized_length(&self) -> Result<u32, Error> {
+        u32::try_from(self.serialized_length()).map_err(|_| Error::OutOfMemory)
+    }
 }
 
 /// A type which can be deserialized from a `Vec<u8>`.
@@ -66,6 +95,11 @@ pub trait FromBytes: Sized {
     fn from_vec(bytes: Vec<u8>) -> Result<(Self, Vec<u8>), Error> {
         Self::from_bytes(bytes.as_slice()).map(|(x, remainder)| (x, Vec::from(remainder)))
     }
+    /// Deserializes the slice into `Self`, returning the number of bytes consumed rather than the
+    /// remainder.
+    fn from_bytes_counted(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        Self::from_bytes(bytes).map(|(x, remainder)| (x, bytes.len() - remainder.len()))
+    }
 }
 
 /// Returns a `Vec<u8>` initialized with sufficient capacity to hold `to_be_serialized` after
@@ -78,11 +112,8 @@ pub fn unchecked_allocate_buffer<T: ToBytes>(to_be_serialized: &T) -> Vec<u8> {
 /// Returns a `Vec<u8>` initialized with sufficient capacity to hold `to_be_serialized` after
 /// serialization, or an error if the capacity would exceed `u32::max_value()`.
 pub fn allocate_buffer<T: ToBytes>(to_be_serialized: &T) -> Result<Vec<u8>, Error> {
-    let serialized_length = to_be_serialized.serialized_length();
-    if serialized_length > u32::max_value() as usize {
-        return Err(Error::OutOfMemory);
-    }
-    Ok(Vec::with_capacity(serialized_length))
+    let serialized_length = to_be_serialized.try_serialized_length()?;
+    Ok(Vec::with_capacity(serialized_length as usize))
 }
 
 /// Serialization and deserialization errors.
@@ -103,6 +134,18 @@ pub enum Error {
     OutOfMemory,
 }
 
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::EarlyEndOfStream => std::io::ErrorKind::UnexpectedEof,
+            Error::Formatting | Error::LeftOverBytes => std::io::ErrorKind::InvalidData,
+            Error::OutOfMemory => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, error.to_string())
+    }
+}
+
 #[cfg(not(feature = "no-unstable-features"))]
 impl From<TryReserveError> for Error {
     fn from(_: TryReserveError) -> Error {
@@ -128,6 +171,70 @@ pub fn serialize(t: impl ToBytes) -> Result<Vec<u8>, Error> {
     t.into_bytes()
 }
 
+/// Serializes `values` into a single framed `Vec<u8>` from which each value can later be split out
+/// individually.
+///
+/// The output comprises a `u32` count of the values, followed by each serialized value prefixed by
+/// its length as a `u32`.
+pub fn serialize_framed<T: ToBytes>(values: &[T]) -> Result<Vec<u8>, Error> {
+    let frames = values
+        .iter()
+        .map(ToBytes::to_bytes)
+        .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+    frames.into_bytes()
+}
+
+/// Deserializes `bytes` produced by [`serialize_framed`] back into the individual values.
+///
+/// Returns an error if any frame cannot be deserialized into `T` or if not all of the bytes of the
+/// input or of any frame are consumed in the operation.
+pub fn deserialize_framed<T: FromBytes>(bytes: Vec<u8>) -> Result<Vec<T>, Error> {
+    let frames: Vec<Vec<u8>> = deserialize(bytes)?;
+    frames.into_iter().map(deserialize).collect()
+}
+
+/// Serializes `t` into `buffer`, replacing its previous contents, and returns the serialized bytes.
+///
+/// Reusing a single buffer to serialize many values avoids allocating a new `Vec<u8>` for each.
+pub fn serialize_reuse<'a, T: ToBytes>(t: &T, buffer: &'a mut Vec<u8>) -> Result<&'a [u8], Error> {
+    buffer.clear();
+    t.to_bytes_into(buffer)?;
+    Ok(buffer.as_slice())
+}
+
+/// Serializes `t` into a `Vec<u8>` prefixed by the single byte `format_version`.
+///
+/// This is intended for tooling which needs to detect format drift; the canonical serialized form
+/// of a value is the untagged one produced by [`serialize`].
+pub fn serialize_tagged(t: impl ToBytes, format_version: u8) -> Result<Vec<u8>, Error> {
+    let mut result = try_vec_with_capacity(U8_SERIALIZED_LENGTH + t.serialized_length())?;
+    result.push(format_version);
+    t.to_bytes_into(&mut result)?;
+    Ok(result)
+}
+
+/// Deserializes `bytes` produced by [`serialize_tagged`], returning the format version byte and
+/// the value.
+pub fn deserialize_tagged<T: FromBytes>(bytes: Vec<u8>) -> Result<(u8, T), Error> {
+    let (format_version, remainder) = u8::from_bytes(&bytes)?;
+    let (t, remainder) = T::from_bytes(remainder)?;
+    if remainder.is_empty() {
+        Ok((format_version, t))
+    } else {
+        Err(Error::LeftOverBytes)
+    }
+}
+
+/// Returns the body of the length-prefixed byte blob at the start of `bytes`, along with the
+/// remainder of `bytes`.
+///
+/// The blob is expected in the form produced by serializing a `Vec<u8>`.  Unlike
+/// `Vec::<u8>::from_bytes()`, the body is borrowed rather than copied.
+pub fn bytes_from_slice(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (size, remainder) = u32::from_bytes(bytes)?;
+    safe_split_at(remainder, size as usize)
+}
+
 pub(crate) fn safe_split_at(bytes: &[u8], n: usize) -> Result<(&[u8], &[u8]), Error> {
     if n > bytes.len() {
         Err(Error::EarlyEndOfStream)
@@ -141,6 +248,10 @@ impl ToBytes for () {
         Ok(Vec::new())
     }
 
+    fn to_bytes_into(&self, _buffer: &mut Vec<u8>) -> Result<(), Error> {
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         UNIT_SERIALIZED_LENGTH
     }
@@ -157,6 +268,11 @@ impl ToBytes for bool {
         u8::from(*self).to_bytes()
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.push(u8::from(*self));
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         BOOL_SERIALIZED_LENGTH
     }
@@ -180,6 +296,11 @@ impl ToBytes for u8 {
         Ok(vec![*self])
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.push(*self);
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U8_SERIALIZED_LENGTH
     }
@@ -194,11 +315,64 @@ impl FromBytes for u8 {
     }
 }
 
+impl ToBytes for i8 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        I8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for i8 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; I8_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, I8_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<i8>::from_le_bytes(result), remainder))
+    }
+}
+
+impl ToBytes for i16 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        I16_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for i16 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; I16_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, I16_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<i16>::from_le_bytes(result), remainder))
+    }
+}
+
 impl ToBytes for i32 {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         Ok(self.to_le_bytes().to_vec())
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         I32_SERIALIZED_LENGTH
     }
@@ -218,6 +392,11 @@ impl ToBytes for i64 {
         Ok(self.to_le_bytes().to_vec())
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         I64_SERIALIZED_LENGTH
     }
@@ -237,6 +416,11 @@ impl ToBytes for u16 {
         Ok(self.to_le_bytes().to_vec())
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U16_SERIALIZED_LENGTH
     }
@@ -256,6 +440,11 @@ impl ToBytes for u32 {
         Ok(self.to_le_bytes().to_vec())
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U32_SERIALIZED_LENGTH
     }
@@ -275,6 +464,11 @@ impl ToBytes for u64 {
         Ok(self.to_le_bytes().to_vec())
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U64_SERIALIZED_LENGTH
     }
@@ -289,11 +483,111 @@ impl FromBytes for u64 {
     }
 }
 
+impl ToBytes for i128 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        I128_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for i128 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; I128_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, I128_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<i128>::from_le_bytes(result), remainder))
+    }
+}
+
+impl ToBytes for u128 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        U128_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for u128 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; U128_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, U128_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<u128>::from_le_bytes(result), remainder))
+    }
+}
+
+impl ToBytes for f32 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        F32_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for f32 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; F32_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, F32_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<f32>::from_le_bytes(result), remainder))
+    }
+}
+
+impl ToBytes for f64 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        buffer.extend_from_slice(&self.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialized_length(&self) -> usize {
+        F64_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for f64 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let mut result = [0u8; F64_SERIALIZED_LENGTH];
+        let (bytes, remainder) = safe_split_at(bytes, F64_SERIALIZED_LENGTH)?;
+        result.copy_from_slice(bytes);
+        Ok((<f64>::from_le_bytes(result), remainder))
+    }
+}
+
 impl ToBytes for String {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         self.as_str().to_bytes()
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        self.as_str().to_bytes_into(buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         self.as_str().serialized_length()
     }
@@ -301,27 +595,37 @@ impl ToBytes for String {
 
 impl FromBytes for String {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (str_bytes, rem): (Vec<u8>, &[u8]) = FromBytes::from_bytes(bytes)?;
-        let result = String::from_utf8(str_bytes).map_err(|_| Error::Formatting)?;
-        Ok((result, rem))
+        let (str_bytes, rem) = bytes_from_slice(bytes)?;
+        let result = str::from_utf8(str_bytes).map_err(|_| Error::Formatting)?;
+        Ok((String::from(result), rem))
     }
 }
 
 #[allow(clippy::ptr_arg)]
 fn vec_to_bytes<T: ToBytes>(vec: &Vec<T>) -> Result<Vec<u8>, Error> {
     let mut result = allocate_buffer(vec)?;
-    result.append(&mut (vec.len() as u32).to_bytes()?);
+    vec_to_bytes_into(vec, &mut result)?;
+    Ok(result)
+}
+
+/// Returns `len` as a `u32` length prefix, or `Error::OutOfMemory` if it would be truncated.
+fn u32_from_len(len: usize) -> Result<u32, Error> {
+    u32::try_from(len).map_err(|_| Error::OutOfMemory)
+}
+
+fn vec_to_bytes_into<T: ToBytes>(vec: &[T], buffer: &mut Vec<u8>) -> Result<(), Error> {
+    u32_from_len(vec.len())?.to_bytes_into(buffer)?;
 
     for item in vec.iter() {
-        result.append(&mut item.to_bytes()?);
+        item.to_bytes_into(buffer)?;
     }
 
-    Ok(result)
+    Ok(())
 }
 
 fn vec_into_bytes<T: ToBytes>(vec: Vec<T>) -> Result<Vec<u8>, Error> {
     let mut result = allocate_buffer(&vec)?;
-    result.append(&mut (vec.len() as u32).to_bytes()?);
+    result.append(&mut u32_from_len(vec.len())?.to_bytes()?);
 
     for item in vec {
         result.append(&mut item.into_bytes()?);
@@ -344,6 +648,10 @@ impl<T: ToBytes> ToBytes for Vec<T> {
         vec_into_bytes(self)
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        vec_to_bytes_into(self, buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         vec_serialized_length(self)
     }
@@ -359,6 +667,10 @@ impl<T: ToBytes> ToBytes for Vec<T> {
         vec_into_bytes(self)
     }
 
+    default fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        vec_to_bytes_into(self, buffer)
+    }
+
     default fn serialized_length(&self) -> usize {
         vec_serialized_length(self)
     }
@@ -394,7 +706,14 @@ fn try_vec_with_capacity<T>(capacity: usize) -> Result<Vec<T>, Error> {
 fn vec_from_bytes<T: FromBytes>(bytes: &[u8]) -> Result<(Vec<T>, &[u8]), Error> {
     let (count, mut stream) = u32::from_bytes(bytes)?;
 
-    let mut result = try_vec_with_capacity(count as usize)?;
+    // The claimed count is untrusted, so don't reserve more memory up front than the number of
+    // bytes remaining.  If more elements than that are actually present, the vector grows as they
+    // are parsed; if the count is too large, parsing then fails early with `EarlyEndOfStream`.
+    let capacity = match mem::size_of::<T>() {
+        0 => count as usize,
+        elem_size => cmp::min(count as usize, stream.len() / elem_size),
+    };
+    let mut result = try_vec_with_capacity(capacity)?;
     for _ in 0..count {
         let (value, remainder) = T::from_bytes(stream)?;
         result.push(value);
@@ -434,18 +753,24 @@ impl<T: FromBytes> FromBytes for Vec<T> {
 impl ToBytes for Vec<u8> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
-        result.append(&mut (self.len() as u32).to_bytes()?);
+        result.append(&mut u32_from_len(self.len())?.to_bytes()?);
         result.extend(self);
         Ok(result)
     }
 
     fn into_bytes(mut self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(&self)?;
-        result.append(&mut (self.len() as u32).to_bytes()?);
+        result.append(&mut u32_from_len(self.len())?.to_bytes()?);
         result.append(&mut self);
         Ok(result)
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        u32_from_len(self.len())?.to_bytes_into(buffer)?;
+        buffer.extend(self);
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U32_SERIALIZED_LENGTH + self.len()
     }
@@ -454,8 +779,7 @@ impl ToBytes for Vec<u8> {
 #[cfg(not(feature = "no-unstable-features"))]
 impl FromBytes for Vec<u8> {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (size, remainder) = u32::from_bytes(bytes)?;
-        let (result, remainder) = safe_split_at(remainder, size as usize)?;
+        let (result, remainder) = bytes_from_slice(bytes)?;
         Ok((result.to_vec(), remainder))
     }
 
@@ -572,6 +896,11 @@ macro_rules! impl_to_from_bytes_for_byte_array {
                     Ok(self.to_vec())
                 }
 
+                fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+                    buffer.extend_from_slice(self);
+                    Ok(())
+                }
+
                 fn serialized_length(&self) -> usize { $len }
             }
 
@@ -599,15 +928,19 @@ impl_to_from_bytes_for_byte_array! {
 impl<V: ToBytes> ToBytes for BTreeSet<V> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
+        self.to_bytes_into(&mut result)?;
+        Ok(result)
+    }
 
-        let num_keys = self.len() as u32;
-        result.append(&mut num_keys.to_bytes()?);
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        let num_keys = u32_from_len(self.len())?;
+        num_keys.to_bytes_into(buffer)?;
 
         for value in self.iter() {
-            result.append(&mut value.to_bytes()?);
+            value.to_bytes_into(buffer)?;
         }
 
-        Ok(result)
+        Ok(())
     }
 
     fn serialized_length(&self) -> usize {
@@ -635,16 +968,20 @@ where
 {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
+        self.to_bytes_into(&mut result)?;
+        Ok(result)
+    }
 
-        let num_keys = self.len() as u32;
-        result.append(&mut num_keys.to_bytes()?);
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        let num_keys = u32_from_len(self.len())?;
+        num_keys.to_bytes_into(buffer)?;
 
         for (key, value) in self.iter() {
-            result.append(&mut key.to_bytes()?);
-            result.append(&mut value.to_bytes()?);
+            key.to_bytes_into(buffer)?;
+            value.to_bytes_into(buffer)?;
         }
 
-        Ok(result)
+        Ok(())
     }
 
     fn serialized_length(&self) -> usize {
@@ -678,18 +1015,25 @@ impl<T: ToBytes> ToBytes for Option<T> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         match self {
             None => Ok(vec![0]),
-            Some(v) => {
+            Some(_) => {
                 let mut result = allocate_buffer(self)?;
-                result.push(1);
-
-                let mut value = v.to_bytes()?;
-                result.append(&mut value);
-
+                self.to_bytes_into(&mut result)?;
                 Ok(result)
             }
         }
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        match self {
+            None => buffer.push(0),
+            Some(v) => {
+                buffer.push(1);
+                v.to_bytes_into(buffer)?;
+            }
+        }
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U8_SERIALIZED_LENGTH
             + match self {
@@ -716,15 +1060,23 @@ impl<T: FromBytes> FromBytes for Option<T> {
 impl<T: ToBytes, E: ToBytes> ToBytes for Result<T, E> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
-        let (variant, mut value) = match self {
-            Err(error) => (0, error.to_bytes()?),
-            Ok(result) => (1, result.to_bytes()?),
-        };
-        result.push(variant);
-        result.append(&mut value);
+        self.to_bytes_into(&mut result)?;
         Ok(result)
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        match self {
+            Err(error) => {
+                buffer.push(0);
+                error.to_bytes_into(buffer)
+            }
+            Ok(result) => {
+                buffer.push(1);
+                result.to_bytes_into(buffer)
+            }
+        }
+    }
+
     fn serialized_length(&self) -> usize {
         U8_SERIALIZED_LENGTH
             + match self {
@@ -756,6 +1108,10 @@ impl<T1: ToBytes> ToBytes for (T1,) {
         self.0.to_bytes()
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        self.0.to_bytes_into(buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         self.0.serialized_length()
     }
@@ -771,11 +1127,15 @@ impl<T1: FromBytes> FromBytes for (T1,) {
 impl<T1: ToBytes, T2: ToBytes> ToBytes for (T1, T2) {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
-        result.append(&mut self.0.to_bytes()?);
-        result.append(&mut self.1.to_bytes()?);
+        self.to_bytes_into(&mut result)?;
         Ok(result)
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        self.0.to_bytes_into(buffer)?;
+        self.1.to_bytes_into(buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         self.0.serialized_length() + self.1.serialized_length()
     }
@@ -792,12 +1152,16 @@ impl<T1: FromBytes, T2: FromBytes> FromBytes for (T1, T2) {
 impl<T1: ToBytes, T2: ToBytes, T3: ToBytes> ToBytes for (T1, T2, T3) {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = allocate_buffer(self)?;
-        result.append(&mut self.0.to_bytes()?);
-        result.append(&mut self.1.to_bytes()?);
-        result.append(&mut self.2.to_bytes()?);
+        self.to_bytes_into(&mut result)?;
         Ok(result)
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        self.0.to_bytes_into(buffer)?;
+        self.1.to_bytes_into(buffer)?;
+        self.2.to_bytes_into(buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         self.0.serialized_length() + self.1.serialized_length() + self.2.serialized_length()
     }
@@ -812,14 +1176,61 @@ impl<T1: FromBytes, T2: FromBytes, T3: FromBytes> FromBytes for (T1, T2, T3) {
     }
 }
 
+macro_rules! impl_to_from_bytes_for_tuple {
+    ($($T:ident $t:ident $idx:tt),+) => {
+        impl<$($T: ToBytes),+> ToBytes for ($($T,)+) {
+            fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+                let mut result = allocate_buffer(self)?;
+                self.to_bytes_into(&mut result)?;
+                Ok(result)
+            }
+
+            fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+                $(self.$idx.to_bytes_into(buffer)?;)+
+                Ok(())
+            }
+
+            fn serialized_length(&self) -> usize {
+                0 $(+ self.$idx.serialized_length())+
+            }
+        }
+
+        impl<$($T: FromBytes),+> FromBytes for ($($T,)+) {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+                let remainder = bytes;
+                $(let ($t, remainder) = $T::from_bytes(remainder)?;)+
+                Ok((($($t,)+), remainder))
+            }
+        }
+    };
+}
+
+impl_to_from_bytes_for_tuple!(T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3);
+impl_to_from_bytes_for_tuple!(T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4);
+impl_to_from_bytes_for_tuple!(T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4, T6 t6 5);
+impl_to_from_bytes_for_tuple!(T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4, T6 t6 5, T7 t7 6);
+impl_to_from_bytes_for_tuple!(
+    T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4, T6 t6 5, T7 t7 6, T8 t8 7
+);
+impl_to_from_bytes_for_tuple!(
+    T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4, T6 t6 5, T7 t7 6, T8 t8 7, T9 t9 8
+);
+impl_to_from_bytes_for_tuple!(
+    T1 t1 0, T2 t2 1, T3 t3 2, T4 t4 3, T5 t5 4, T6 t6 5, T7 t7 6, T8 t8 7, T9 t9 8, T10 t10 9
+);
+
 impl ToBytes for str {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        if self.len() > u32::max_value() as usize - U32_SERIALIZED_LENGTH {
-            return Err(Error::OutOfMemory);
-        }
+        self.try_serialized_length()?;
         self.as_bytes().to_vec().into_bytes()
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        u32_from_len(self.len())?.to_bytes_into(buffer)?;
+        buffer.extend_from_slice(self.as_bytes());
+        Ok(())
+    }
+
     fn serialized_length(&self) -> usize {
         U32_SERIALIZED_LENGTH + self.as_bytes().len()
     }
@@ -830,6 +1241,10 @@ impl ToBytes for &str {
         (*self).to_bytes()
     }
 
+    fn to_bytes_into(&self, buffer: &mut Vec<u8>) -> Result<(), Error> {
+        (*self).to_bytes_into(buffer)
+    }
+
     fn serialized_length(&self) -> usize {
         (*self).serialized_length()
     }
@@ -907,20 +1322,81 @@ mod tests {
         // Assert the `INSTANCE_COUNT` has dropped to zero again.
         INSTANCE_COUNT.with(|count| assert_eq!(0, *count.borrow()));
     }
+
+    #[test]
+    fn from_bytes_counted_should_return_consumed_length() {
+        let value = (7u32, String::from("hello"));
+        let mut bytes = value.to_bytes().unwrap();
+        bytes.extend_from_slice(&[1, 2, 3]);
+
+        let expected_count = U32_SERIALIZED_LENGTH + U32_SERIALIZED_LENGTH + "hello".len();
+        let (parsed, count) = <(u32, String)>::from_bytes_counted(&bytes).unwrap();
+        assert_eq!(parsed, value);
+        assert_eq!(count, expected_count);
+        assert_eq!(count, bytes.len() - 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_convert_into_io_error() {
+        let io_error = std::io::Error::from(Error::EarlyEndOfStream);
+        assert_eq!(io_error.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(io_error.to_string(), Error::EarlyEndOfStream.to_string());
+
+        let io_error = std::io::Error::from(Error::LeftOverBytes);
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), Error::LeftOverBytes.to_string());
+    }
+
+    // Only on 64-bit hosts can a length exceed `u32::max_value()`. The helper is exercised directly
+    // rather than via a collection of that length to avoid allocating 4 GiB.
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn length_prefix_should_not_be_truncated() {
+        let max = u32::max_value() as usize;
+        assert_eq!(u32_from_len(max), Ok(u32::max_value()));
+        assert_eq!(u32_from_len(max + 1), Err(Error::OutOfMemory));
+        assert_eq!(u32_from_len(usize::max_value()), Err(Error::OutOfMemory));
+    }
 }
 
 #[cfg(test)]
 mod proptests {
-    use std::vec::Vec;
+    use std::{boxed::Box, string::String, vec::Vec};
 
     use proptest::{collection::vec, prelude::*};
 
     use crate::{
         bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
         gens::*,
+        Key,
     };
 
     proptest! {
+        #[test]
+        fn test_framed_keys(keys in vec(key_arb(), 0..20)) {
+            let framed = bytesrepr::serialize_framed(&keys).unwrap();
+            let parsed: Vec<Key> = bytesrepr::deserialize_framed(framed.clone()).unwrap();
+            prop_assert_eq!(&parsed, &keys);
+
+            let mut with_trailing_byte = framed;
+            with_trailing_byte.push(0);
+            prop_assert_eq!(
+                bytesrepr::deserialize_framed::<Key>(with_trailing_byte),
+                Err(bytesrepr::Error::LeftOverBytes)
+            );
+        }
+
+        #[test]
+        fn test_vec_vec_key(keys in vec(vec(key_arb(), 0..5), 0..5)) {
+            bytesrepr::test_serialization_roundtrip(&keys);
+        }
+
+        #[test]
+        fn test_vec_option_uref(urefs in vec(proptest::option::of(uref_arb()), 0..10)) {
+            bytesrepr::test_serialization_roundtrip(&urefs);
+        }
+
         #[test]
         fn test_bool(u in any::<bool>()) {
             bytesrepr::test_serialization_roundtrip(&u);
@@ -936,6 +1412,21 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&u);
         }
 
+        #[test]
+        fn test_i8(i in any::<i8>()) {
+            bytesrepr::test_serialization_roundtrip(&i);
+        }
+
+        #[test]
+        fn test_i16(i in any::<i16>()) {
+            bytesrepr::test_serialization_roundtrip(&i);
+        }
+
+        #[test]
+        fn test_u16_tuple(t in (any::<u16>(), any::<u16>(), any::<u16>())) {
+            bytesrepr::test_serialization_roundtrip(&t);
+        }
+
         #[test]
         fn test_u32(u in any::<u32>()) {
             bytesrepr::test_serialization_roundtrip(&u);
@@ -956,6 +1447,42 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&u);
         }
 
+        #[test]
+        fn test_i128(i in any::<i128>()) {
+            bytesrepr::test_serialization_roundtrip(&i);
+        }
+
+        #[test]
+        fn test_native_u128(u in any::<u128>()) {
+            bytesrepr::test_serialization_roundtrip(&u);
+        }
+
+        #[test]
+        fn test_f32(f in any::<f32>().prop_filter("finite", |f| f.is_finite())) {
+            bytesrepr::test_serialization_roundtrip(&f);
+        }
+
+        #[test]
+        fn test_f64(f in any::<f64>().prop_filter("finite", |f| f.is_finite())) {
+            bytesrepr::test_serialization_roundtrip(&f);
+        }
+
+        #[test]
+        fn test_f32_bits(bits in any::<u32>()) {
+            // Covers NaN and infinities, which must round trip bit-for-bit.
+            let f = f32::from_bits(bits);
+            let (parsed, _) = f32::from_bytes(&f.to_bytes().unwrap()).unwrap();
+            prop_assert_eq!(f.to_bits(), parsed.to_bits());
+        }
+
+        #[test]
+        fn test_f64_bits(bits in any::<u64>()) {
+            // Covers NaN and infinities, which must round trip bit-for-bit.
+            let f = f64::from_bits(bits);
+            let (parsed, _) = f64::from_bytes(&f.to_bytes().unwrap()).unwrap();
+            prop_assert_eq!(f.to_bits(), parsed.to_bits());
+        }
+
         #[test]
         fn test_u8_slice_32(s in u8_slice_32()) {
             bytesrepr::test_serialization_roundtrip(&s);
@@ -1061,6 +1588,62 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&sem_ver);
         }
 
+        #[test]
+        fn test_to_bytes_into_matches_to_bytes(
+            v in vec(vec(any::<u8>(), 0..10), 0..10),
+            m in named_keys_arb(10),
+            o in proptest::option::of(key_arb()),
+            t in (any::<u8>(), any::<u32>(), "\\PC*"),
+            s in "\\PC*",
+        ) {
+            let mut buffer = Vec::new();
+            v.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &v.to_bytes().unwrap());
+
+            buffer.clear();
+            m.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &m.to_bytes().unwrap());
+
+            buffer.clear();
+            o.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &o.to_bytes().unwrap());
+
+            buffer.clear();
+            t.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &t.to_bytes().unwrap());
+
+            buffer.clear();
+            s.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &s.to_bytes().unwrap());
+
+            buffer.clear();
+            s.as_str().to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &s.as_str().to_bytes().unwrap());
+        }
+
+        #[test]
+        fn test_primitive_to_bytes_into_matches_to_bytes(
+            p in (any::<bool>(), any::<i8>(), any::<u16>()),
+            q in (any::<i64>(), any::<u128>(), any::<f64>()),
+            r in any::<Result<u32, bool>>(),
+        ) {
+            let mut buffer = Vec::new();
+            p.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &p.to_bytes().unwrap());
+
+            buffer.clear();
+            q.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &q.to_bytes().unwrap());
+
+            buffer.clear();
+            r.to_bytes_into(&mut buffer).unwrap();
+            prop_assert_eq!(&buffer, &r.to_bytes().unwrap());
+
+            buffer.clear();
+            ().to_bytes_into(&mut buffer).unwrap();
+            prop_assert!(buffer.is_empty());
+        }
+
         #[test]
         fn test_tuple1(t in (any::<u8>(),)) {
             bytesrepr::test_serialization_roundtrip(&t);
@@ -1075,6 +1658,204 @@ mod proptests {
         fn test_tuple3(t in (any::<u8>(),any::<u32>(),any::<i32>())) {
             bytesrepr::test_serialization_roundtrip(&t);
         }
+
+        #[test]
+        fn test_tuple4(t in (any::<u8>(),any::<u32>(),any::<i32>(),any::<u64>())) {
+            bytesrepr::test_serialization_roundtrip(&t);
+        }
+
+        #[test]
+        fn test_tuple7(
+            t in (
+                any::<u64>(),
+                "\\PC*",
+                key_arb(),
+                any::<u8>(),
+                any::<bool>(),
+                any::<i64>(),
+                u512_arb(),
+            )
+        ) {
+            bytesrepr::test_serialization_roundtrip(&t);
+        }
+
+        #[test]
+        fn test_tuple10(
+            t in (
+                any::<u8>(),
+                any::<u16>(),
+                any::<u32>(),
+                any::<u64>(),
+                any::<i8>(),
+                any::<i16>(),
+                any::<i32>(),
+                any::<i64>(),
+                any::<bool>(),
+                "\\PC*",
+            )
+        ) {
+            bytesrepr::test_serialization_roundtrip(&t);
+        }
+    }
+
+    #[test]
+    fn native_128_bit_integer_bounds_round_trip() {
+        bytesrepr::test_serialization_roundtrip(&i128::min_value());
+        bytesrepr::test_serialization_roundtrip(&i128::max_value());
+        bytesrepr::test_serialization_roundtrip(&u128::min_value());
+        bytesrepr::test_serialization_roundtrip(&u128::max_value());
+        assert_eq!(i128::max_value().to_bytes().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn huge_vec_length_prefix_should_fail_with_early_end_of_stream() {
+        let mut bytes = u32::max_value().to_bytes().unwrap();
+        bytes.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(
+            Vec::<u64>::from_bytes(&bytes),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+        assert_eq!(
+            Vec::<bool>::from_bytes(&bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+        assert_eq!(
+            Vec::<u8>::from_bytes(&bytes),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+
+        // Zero-length elements must still be accepted in any number.
+        let count = 1_000_u32;
+        let (units, remainder) = Vec::<()>::from_bytes(&count.to_bytes().unwrap()).unwrap();
+        assert_eq!(units.len(), count as usize);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn huge_string_length_prefix_should_fail_with_early_end_of_stream() {
+        let mut bytes = (1_u32 << 31).to_bytes().unwrap();
+        bytes.extend_from_slice(b"short body");
+        assert_eq!(String::from_bytes(&bytes), Err(bytesrepr::Error::EarlyEndOfStream));
+    }
+
+    #[test]
+    fn invalid_utf8_string_should_fail_with_formatting_error() {
+        let mut bytes = 2_u32.to_bytes().unwrap();
+        bytes.extend_from_slice(&[0xc3, 0x28]);
+        assert_eq!(String::from_bytes(&bytes), Err(bytesrepr::Error::Formatting));
+    }
+
+    #[test]
+    fn try_serialized_length_should_agree_with_to_bytes_at_boundary() {
+        struct FixedLength(usize);
+
+        impl ToBytes for FixedLength {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                unreachable!("should not be serialized")
+            }
+
+            fn serialized_length(&self) -> usize {
+                self.0
+            }
+        }
+
+        let max = u32::max_value() as usize;
+        let at_limit = vec![FixedLength(max - U32_SERIALIZED_LENGTH)];
+        assert_eq!(at_limit.try_serialized_length(), Ok(u32::max_value()));
+
+        let over_limit = vec![FixedLength(max - U32_SERIALIZED_LENGTH + 1)];
+        assert_eq!(over_limit.serialized_length(), max + 1);
+        assert_eq!(over_limit.try_serialized_length(), Err(bytesrepr::Error::OutOfMemory));
+        assert_eq!(over_limit.to_bytes(), Err(bytesrepr::Error::OutOfMemory));
+    }
+
+    #[test]
+    fn bytes_from_slice_should_agree_with_owned_vec() {
+        let mut buffer = vec![1u8, 2, 3].to_bytes().unwrap();
+        buffer.extend_from_slice(&[9, 8]);
+
+        let (borrowed, borrowed_remainder) = bytesrepr::bytes_from_slice(&buffer).unwrap();
+        let (owned, owned_remainder) = Vec::<u8>::from_bytes(&buffer).unwrap();
+        assert_eq!(borrowed, &[1, 2, 3]);
+        assert_eq!(borrowed, owned.as_slice());
+        assert_eq!(borrowed_remainder, &[9, 8]);
+        assert_eq!(borrowed_remainder, owned_remainder);
+
+        assert_eq!(
+            bytesrepr::bytes_from_slice(&buffer[..5]),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+    }
+
+    #[test]
+    fn to_bytes_should_be_usable_as_trait_object() {
+        let values: Vec<Box<dyn ToBytes>> = vec![Box::new(7u32), Box::new(String::from("abc"))];
+        let mut buffer = Vec::new();
+        for value in values.iter() {
+            value.to_bytes_into(&mut buffer).unwrap();
+        }
+        let total_length: usize = values.iter().map(|value| value.serialized_length()).sum();
+        assert_eq!(buffer.len(), total_length);
+
+        let (number, remainder) = u32::from_bytes(&buffer).unwrap();
+        let (string, remainder) = String::from_bytes(remainder).unwrap();
+        assert_eq!(number, 7);
+        assert_eq!(string, "abc");
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn serialize_reuse_should_match_owned_serialization() {
+        let mut buffer = vec![255u8; 100];
+        for value in &[String::from("abc"), String::new(), String::from("de")] {
+            let expected = bytesrepr::serialize(value.clone()).unwrap();
+            let reused = bytesrepr::serialize_reuse(value, &mut buffer).unwrap();
+            assert_eq!(reused, expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn tagged_should_round_trip_with_format_version() {
+        let value = vec![String::from("a"), String::from("bc")];
+        let tagged = bytesrepr::serialize_tagged(value.clone(), 3).unwrap();
+        assert_eq!(tagged[0], 3);
+        assert_eq!(&tagged[1..], value.to_bytes().unwrap().as_slice());
+        let (format_version, parsed): (u8, Vec<String>) =
+            bytesrepr::deserialize_tagged(tagged).unwrap();
+        assert_eq!(format_version, 3);
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn tagged_should_fail_on_short_buffer() {
+        assert_eq!(
+            bytesrepr::deserialize_tagged::<u32>(vec![]),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+        assert_eq!(
+            bytesrepr::deserialize_tagged::<u32>(vec![1, 2, 3]),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+        assert_eq!(
+            bytesrepr::deserialize_tagged::<u8>(vec![1, 2, 3]),
+            Err(bytesrepr::Error::LeftOverBytes)
+        );
+    }
+
+    #[test]
+    fn to_bytes_into_should_append_to_existing_buffer() {
+        let value: Vec<Vec<u8>> = vec![vec![1, 2, 3], vec![], vec![4]];
+        let mut buffer = vec![255u8];
+        value.to_bytes_into(&mut buffer).unwrap();
+        assert_eq!(buffer[0], 255);
+        assert_eq!(&buffer[1..], value.to_bytes().unwrap().as_slice());
+    }
+
+    #[test]
+    fn short_input_should_fail_with_early_end_of_stream() {
+        assert_eq!(u16::from_bytes(&[1]), Err(bytesrepr::Error::EarlyEndOfStream));
+        assert_eq!(i16::from_bytes(&[1]), Err(bytesrepr::Error::EarlyEndOfStream));
+        assert_eq!(i8::from_bytes(&[]), Err(bytesrepr::Error::EarlyEndOfStream));
     }
 
     #[test]
@@ -1,14 +1,23 @@
 use alloc::{
     boxed::Box,
     collections::{BTreeMap, VecDeque},
+    format,
     string::String,
     vec::Vec,
 };
-use core::mem;
+use core::{
+    fmt::{self, Display, Formatter},
+    mem,
+};
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes},
-    Key, URef, U128, U256, U512,
+    bytesrepr::{
+        self, FromBytes, ToBytes, BOOL_SERIALIZED_LENGTH, F32_SERIALIZED_LENGTH,
+        F64_SERIALIZED_LENGTH, I32_SERIALIZED_LENGTH, I64_SERIALIZED_LENGTH,
+        U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH,
+        UNIT_SERIALIZED_LENGTH,
+    },
+    Key, URef, U128, U256, U512, UREF_SERIALIZED_LENGTH,
 };
 
 const CL_TYPE_TAG_BOOL: u8 = 0;
@@ -33,6 +42,20 @@ const CL_TYPE_TAG_TUPLE1: u8 = 18;
 const CL_TYPE_TAG_TUPLE2: u8 = 19;
 const CL_TYPE_TAG_TUPLE3: u8 = 20;
 const CL_TYPE_TAG_ANY: u8 = 21;
+const CL_TYPE_TAG_F32: u8 = 22;
+const CL_TYPE_TAG_F64: u8 = 23;
+const CL_TYPE_TAG_TUPLE4: u8 = 24;
+const CL_TYPE_TAG_TUPLE5: u8 = 25;
+const CL_TYPE_TAG_TUPLE6: u8 = 26;
+const CL_TYPE_TAG_TUPLE7: u8 = 27;
+const CL_TYPE_TAG_TUPLE8: u8 = 28;
+const CL_TYPE_TAG_TUPLE9: u8 = 29;
+const CL_TYPE_TAG_TUPLE10: u8 = 30;
+const CL_TYPE_TAG_BYTE_ARRAY: u8 = 31;
+
+/// The maximum depth of nested [`CLType`]s permitted when deserializing. Deeper nesting is
+/// rejected to avoid exhausting the stack.
+const CL_TYPE_RECURSION_DEPTH: u8 = 50;
 
 /// CasperLabs types, i.e. types which can be stored and manipulated by smart contracts.
 ///
@@ -51,6 +74,10 @@ pub enum CLType {
     U32,
     /// `u64` primitive.
     U64,
+    /// `f32` primitive.
+    F32,
+    /// `f64` primitive.
+    F64,
     /// [`U128`] large unsigned integer type.
     U128,
     /// [`U256`] large unsigned integer type.
@@ -71,6 +98,14 @@ pub enum CLType {
     List(Box<CLType>),
     /// Fixed-length list of a single `CLType` (comparable to a Rust array).
     FixedList(Box<CLType>, u32),
+    /// Fixed-length array of bytes (comparable to a Rust `[u8; N]`).
+    ///
+    /// Byte arrays were previously described as `FixedList(Box::new(CLType::U8), N)`. The
+    /// serialized value is identical in both cases (`N` raw bytes with no length prefix), but the
+    /// serialized type is one byte shorter, and consumers can treat the value as a single blob
+    /// rather than as a list of `U8` elements. [`CLValue::into_t`](crate::CLValue::into_t) still
+    /// accepts values described by the old type.
+    ByteArray(u32),
     /// `Result` with `Ok` and `Err` variants of `CLType`s.
     #[allow(missing_docs)] // generated docs are explicit enough.
     Result { ok: Box<CLType>, err: Box<CLType> },
@@ -86,6 +121,20 @@ pub enum CLType {
     Tuple2([Box<CLType>; 2]),
     /// 3-ary tuple of `CLType`s.
     Tuple3([Box<CLType>; 3]),
+    /// 4-ary tuple of `CLType`s.
+    Tuple4([Box<CLType>; 4]),
+    /// 5-ary tuple of `CLType`s.
+    Tuple5([Box<CLType>; 5]),
+    /// 6-ary tuple of `CLType`s.
+    Tuple6([Box<CLType>; 6]),
+    /// 7-ary tuple of `CLType`s.
+    Tuple7([Box<CLType>; 7]),
+    /// 8-ary tuple of `CLType`s.
+    Tuple8([Box<CLType>; 8]),
+    /// 9-ary tuple of `CLType`s.
+    Tuple9([Box<CLType>; 9]),
+    /// 10-ary tuple of `CLType`s.
+    Tuple10([Box<CLType>; 10]),
     /// Unspecified type.
     Any,
 }
@@ -101,6 +150,8 @@ impl CLType {
                 | CLType::U8
                 | CLType::U32
                 | CLType::U64
+                | CLType::F32
+                | CLType::F64
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
@@ -113,13 +164,162 @@ impl CLType {
                 CLType::FixedList(cl_type, list_len) => {
                     cl_type.serialized_length() + list_len.to_le_bytes().len()
                 }
+                CLType::ByteArray(len) => len.to_le_bytes().len(),
                 CLType::Result { ok, err } => ok.serialized_length() + err.serialized_length(),
                 CLType::Map { key, value } => key.serialized_length() + value.serialized_length(),
                 CLType::Tuple1(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple2(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple3(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple4(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple5(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple6(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple7(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple8(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple9(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
+                CLType::Tuple10(cl_type_array) => serialized_length_of_cl_tuple_type(cl_type_array),
             }
     }
+
+    /// Returns `true` if `self` is a primitive type, i.e. a boolean, numeric, unit, [`Key`] or
+    /// [`URef`] type.
+    pub fn is_primitive(&self) -> bool {
+        match self {
+            CLType::Bool
+            | CLType::I32
+            | CLType::I64
+            | CLType::U8
+            | CLType::U32
+            | CLType::U64
+            | CLType::F32
+            | CLType::F64
+            | CLType::U128
+            | CLType::U256
+            | CLType::U512
+            | CLType::Unit
+            | CLType::Key
+            | CLType::URef => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the serialized length of every value of type `self`, or `None` if the length
+    /// depends on the value.
+    ///
+    /// Note that large unsigned integers are serialized with leading zero bytes omitted and that
+    /// the variants of [`Key`] have differing lengths, so none of these have a fixed size.
+    pub fn fixed_serialized_size(&self) -> Option<usize> {
+        match self {
+            CLType::Bool => Some(BOOL_SERIALIZED_LENGTH),
+            CLType::I32 => Some(I32_SERIALIZED_LENGTH),
+            CLType::I64 => Some(I64_SERIALIZED_LENGTH),
+            CLType::U8 => Some(U8_SERIALIZED_LENGTH),
+            CLType::U32 => Some(U32_SERIALIZED_LENGTH),
+            CLType::U64 => Some(U64_SERIALIZED_LENGTH),
+            CLType::F32 => Some(F32_SERIALIZED_LENGTH),
+            CLType::F64 => Some(F64_SERIALIZED_LENGTH),
+            CLType::Unit => Some(UNIT_SERIALIZED_LENGTH),
+            CLType::URef => Some(UREF_SERIALIZED_LENGTH),
+            CLType::ByteArray(len) => Some(*len as usize),
+            CLType::FixedList(cl_type, len) => {
+                cl_type.fixed_serialized_size().and_then(|size| size.checked_mul(*len as usize))
+            }
+            CLType::Result { ok, err } => {
+                match (ok.fixed_serialized_size(), err.fixed_serialized_size()) {
+                    (Some(ok_size), Some(err_size)) if ok_size == err_size => {
+                        Some(U8_SERIALIZED_LENGTH + ok_size)
+                    }
+                    _ => None,
+                }
+            }
+            CLType::Tuple1(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple2(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple3(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple4(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple5(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple6(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple7(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple8(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple9(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::Tuple10(cl_type_array) => fixed_serialized_size_of_cl_tuple_type(cl_type_array),
+            CLType::U128
+            | CLType::U256
+            | CLType::U512
+            | CLType::String
+            | CLType::Key
+            | CLType::Option(_)
+            | CLType::List(_)
+            | CLType::Map { .. }
+            | CLType::Any => None,
+        }
+    }
+
+    /// Returns `true` if `self` is structurally equal to `expected`, treating [`CLType::Any`] on
+    /// either side as matching any type, including where it appears nested within either type.
+    ///
+    /// The legacy `FixedList(U8, N)` description of a byte array and [`CLType::ByteArray`]`(N)`
+    /// also match each other at any level, as both describe identically-serialized values.  So
+    /// does `FixedList(Any, N)`, as `Any` may stand in for `U8`.
+    pub fn matches(&self, expected: &CLType) -> bool {
+        cl_types_match(self, expected, true)
+    }
+
+    /// Returns `true` if `self` is equal to `expected`, except that the legacy `FixedList(U8, N)`
+    /// description of a byte array and [`CLType::ByteArray`]`(N)` are treated as equal at any
+    /// level.
+    ///
+    /// Unlike [`CLType::matches`], [`CLType::Any`] only matches itself.  This allows values
+    /// recorded before byte arrays had their own `CLType` to be read as their current type.
+    pub fn is_legacy_equivalent(&self, expected: &CLType) -> bool {
+        cl_types_match(self, expected, false)
+    }
+
+    /// Returns the Rust type which `self` describes, rendered as valid Rust type syntax, e.g.
+    /// `Option<U512>` or `BTreeMap<String, Vec<Key>>`.
+    ///
+    /// Large unsigned integers, [`Key`]s and [`URef`]s are rendered as their names in this crate.
+    /// As there is no concrete Rust type for [`CLType::Any`], it is rendered as `Box<dyn Any>`.
+    pub fn to_rust_type_string(&self) -> String {
+        match self {
+            CLType::Bool => String::from("bool"),
+            CLType::I32 => String::from("i32"),
+            CLType::I64 => String::from("i64"),
+            CLType::U8 => String::from("u8"),
+            CLType::U32 => String::from("u32"),
+            CLType::U64 => String::from("u64"),
+            CLType::F32 => String::from("f32"),
+            CLType::F64 => String::from("f64"),
+            CLType::U128 => String::from("U128"),
+            CLType::U256 => String::from("U256"),
+            CLType::U512 => String::from("U512"),
+            CLType::Unit => String::from("()"),
+            CLType::String => String::from("String"),
+            CLType::Key => String::from("Key"),
+            CLType::URef => String::from("URef"),
+            CLType::Option(cl_type) => format!("Option<{}>", cl_type.to_rust_type_string()),
+            CLType::List(cl_type) => format!("Vec<{}>", cl_type.to_rust_type_string()),
+            CLType::FixedList(cl_type, len) => {
+                format!("[{}; {}]", cl_type.to_rust_type_string(), len)
+            }
+            CLType::ByteArray(len) => format!("[u8; {}]", len),
+            CLType::Result { ok, err } => {
+                format!("Result<{}, {}>", ok.to_rust_type_string(), err.to_rust_type_string())
+            }
+            CLType::Map { key, value } => {
+                format!("BTreeMap<{}, {}>", key.to_rust_type_string(), value.to_rust_type_string())
+            }
+            CLType::Tuple1(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple2(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple3(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple4(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple5(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple6(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple7(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple8(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple9(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Tuple10(cl_type_array) => rust_tuple_type_string(cl_type_array),
+            CLType::Any => String::from("Box<dyn Any>"),
+        }
+    }
 }
 
 /// Returns the `CLType` describing a "named key" on the system, i.e. a `(String, Key)`.
@@ -127,6 +327,45 @@ pub fn named_key_type() -> CLType {
     CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)])
 }
 
+impl Display for CLType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CLType::Bool => write!(f, "Bool"),
+            CLType::I32 => write!(f, "I32"),
+            CLType::I64 => write!(f, "I64"),
+            CLType::U8 => write!(f, "U8"),
+            CLType::U32 => write!(f, "U32"),
+            CLType::U64 => write!(f, "U64"),
+            CLType::F32 => write!(f, "F32"),
+            CLType::F64 => write!(f, "F64"),
+            CLType::U128 => write!(f, "U128"),
+            CLType::U256 => write!(f, "U256"),
+            CLType::U512 => write!(f, "U512"),
+            CLType::Unit => write!(f, "Unit"),
+            CLType::String => write!(f, "String"),
+            CLType::Key => write!(f, "Key"),
+            CLType::URef => write!(f, "URef"),
+            CLType::Option(cl_type) => write!(f, "Option<{}>", cl_type),
+            CLType::List(cl_type) => write!(f, "List<{}>", cl_type),
+            CLType::FixedList(cl_type, len) => write!(f, "FixedList<{}, {}>", cl_type, len),
+            CLType::ByteArray(len) => write!(f, "ByteArray<{}>", len),
+            CLType::Result { ok, err } => write!(f, "Result<{}, {}>", ok, err),
+            CLType::Map { key, value } => write!(f, "Map<{}, {}>", key, value),
+            CLType::Tuple1(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple2(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple3(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple4(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple5(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple6(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple7(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple8(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple9(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Tuple10(cl_type_array) => fmt_cl_tuple_type(f, cl_type_array),
+            CLType::Any => write!(f, "Any"),
+        }
+    }
+}
+
 impl CLType {
     pub(crate) fn append_bytes(&self, stream: &mut Vec<u8>) {
         match self {
@@ -136,6 +375,8 @@ impl CLType {
             CLType::U8 => stream.push(CL_TYPE_TAG_U8),
             CLType::U32 => stream.push(CL_TYPE_TAG_U32),
             CLType::U64 => stream.push(CL_TYPE_TAG_U64),
+            CLType::F32 => stream.push(CL_TYPE_TAG_F32),
+            CLType::F64 => stream.push(CL_TYPE_TAG_F64),
             CLType::U128 => stream.push(CL_TYPE_TAG_U128),
             CLType::U256 => stream.push(CL_TYPE_TAG_U256),
             CLType::U512 => stream.push(CL_TYPE_TAG_U512),
@@ -156,6 +397,10 @@ impl CLType {
                 cl_type.append_bytes(stream);
                 stream.append(&mut len.to_bytes().unwrap());
             }
+            CLType::ByteArray(len) => {
+                stream.push(CL_TYPE_TAG_BYTE_ARRAY);
+                stream.append(&mut len.to_bytes().unwrap());
+            }
             CLType::Result { ok, err } => {
                 stream.push(CL_TYPE_TAG_RESULT);
                 ok.append_bytes(stream);
@@ -175,88 +420,217 @@ impl CLType {
             CLType::Tuple3(cl_type_array) => {
                 serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE3, cl_type_array, stream)
             }
+            CLType::Tuple4(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE4, cl_type_array, stream)
+            }
+            CLType::Tuple5(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE5, cl_type_array, stream)
+            }
+            CLType::Tuple6(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE6, cl_type_array, stream)
+            }
+            CLType::Tuple7(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE7, cl_type_array, stream)
+            }
+            CLType::Tuple8(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE8, cl_type_array, stream)
+            }
+            CLType::Tuple9(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE9, cl_type_array, stream)
+            }
+            CLType::Tuple10(cl_type_array) => {
+                serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE10, cl_type_array, stream)
+            }
             CLType::Any => stream.push(CL_TYPE_TAG_ANY),
         }
     }
 }
 
-#[allow(clippy::cognitive_complexity)]
 impl FromBytes for CLType {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (tag, remainder) = u8::from_bytes(bytes)?;
-        match tag {
-            CL_TYPE_TAG_BOOL => Ok((CLType::Bool, remainder)),
-            CL_TYPE_TAG_I32 => Ok((CLType::I32, remainder)),
-            CL_TYPE_TAG_I64 => Ok((CLType::I64, remainder)),
-            CL_TYPE_TAG_U8 => Ok((CLType::U8, remainder)),
-            CL_TYPE_TAG_U32 => Ok((CLType::U32, remainder)),
-            CL_TYPE_TAG_U64 => Ok((CLType::U64, remainder)),
-            CL_TYPE_TAG_U128 => Ok((CLType::U128, remainder)),
-            CL_TYPE_TAG_U256 => Ok((CLType::U256, remainder)),
-            CL_TYPE_TAG_U512 => Ok((CLType::U512, remainder)),
-            CL_TYPE_TAG_UNIT => Ok((CLType::Unit, remainder)),
-            CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
-            CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
-            CL_TYPE_TAG_UREF => Ok((CLType::URef, remainder)),
-            CL_TYPE_TAG_OPTION => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Option(Box::new(inner_type));
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_LIST => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::List(Box::new(inner_type));
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_FIXED_LIST => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let (len, remainder) = u32::from_bytes(remainder)?;
-                let cl_type = CLType::FixedList(Box::new(inner_type), len);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_RESULT => {
-                let (ok_type, remainder) = CLType::from_bytes(remainder)?;
-                let (err_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Result {
-                    ok: Box::new(ok_type),
-                    err: Box::new(err_type),
-                };
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_MAP => {
-                let (key_type, remainder) = CLType::from_bytes(remainder)?;
-                let (value_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Map {
-                    key: Box::new(key_type),
-                    value: Box::new(value_type),
-                };
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE1 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(1, remainder)?;
-                let cl_type = CLType::Tuple1([inner_types.pop_front().unwrap()]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE2 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(2, remainder)?;
-                let cl_type = CLType::Tuple2([
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                ]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE3 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(3, remainder)?;
-                let cl_type = CLType::Tuple3([
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                ]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
-            _ => Err(bytesrepr::Error::Formatting),
+        depth_limited_from_bytes(0, bytes)
+    }
+}
+
+#[allow(clippy::cognitive_complexity)]
+fn depth_limited_from_bytes(
+    depth: u8,
+    bytes: &[u8],
+) -> Result<(CLType, &[u8]), bytesrepr::Error> {
+    if depth >= CL_TYPE_RECURSION_DEPTH {
+        return Err(bytesrepr::Error::Formatting);
+    }
+    let depth = depth + 1;
+    let (tag, remainder) = u8::from_bytes(bytes)?;
+    match tag {
+        CL_TYPE_TAG_BOOL => Ok((CLType::Bool, remainder)),
+        CL_TYPE_TAG_I32 => Ok((CLType::I32, remainder)),
+        CL_TYPE_TAG_I64 => Ok((CLType::I64, remainder)),
+        CL_TYPE_TAG_U8 => Ok((CLType::U8, remainder)),
+        CL_TYPE_TAG_U32 => Ok((CLType::U32, remainder)),
+        CL_TYPE_TAG_U64 => Ok((CLType::U64, remainder)),
+        CL_TYPE_TAG_F32 => Ok((CLType::F32, remainder)),
+        CL_TYPE_TAG_F64 => Ok((CLType::F64, remainder)),
+        CL_TYPE_TAG_U128 => Ok((CLType::U128, remainder)),
+        CL_TYPE_TAG_U256 => Ok((CLType::U256, remainder)),
+        CL_TYPE_TAG_U512 => Ok((CLType::U512, remainder)),
+        CL_TYPE_TAG_UNIT => Ok((CLType::Unit, remainder)),
+        CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
+        CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
+        CL_TYPE_TAG_UREF => Ok((CLType::URef, remainder)),
+        CL_TYPE_TAG_OPTION => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Option(Box::new(inner_type));
+            Ok((cl_type, remainder))
         }
+        CL_TYPE_TAG_LIST => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::List(Box::new(inner_type));
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_FIXED_LIST => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (len, remainder) = u32::from_bytes(remainder)?;
+            let cl_type = CLType::FixedList(Box::new(inner_type), len);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_BYTE_ARRAY => {
+            let (len, remainder) = u32::from_bytes(remainder)?;
+            Ok((CLType::ByteArray(len), remainder))
+        }
+        CL_TYPE_TAG_RESULT => {
+            let (ok_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (err_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Result {
+                ok: Box::new(ok_type),
+                err: Box::new(err_type),
+            };
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_MAP => {
+            let (key_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (value_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Map {
+                key: Box::new(key_type),
+                value: Box::new(value_type),
+            };
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE1 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 1, remainder)?;
+            let cl_type = CLType::Tuple1([inner_types.pop_front().unwrap()]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE2 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 2, remainder)?;
+            let cl_type = CLType::Tuple2([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE3 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 3, remainder)?;
+            let cl_type = CLType::Tuple3([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE4 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 4, remainder)?;
+            let cl_type = CLType::Tuple4([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE5 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 5, remainder)?;
+            let cl_type = CLType::Tuple5([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE6 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 6, remainder)?;
+            let cl_type = CLType::Tuple6([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE7 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 7, remainder)?;
+            let cl_type = CLType::Tuple7([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE8 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 8, remainder)?;
+            let cl_type = CLType::Tuple8([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE9 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 9, remainder)?;
+            let cl_type = CLType::Tuple9([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE10 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 10, remainder)?;
+            let cl_type = CLType::Tuple10([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
+        _ => Err(bytesrepr::Error::Formatting),
     }
 }
 
@@ -272,12 +646,13 @@ fn serialize_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
 }
 
 fn parse_cl_tuple_types(
+    depth: u8,
     count: usize,
     mut bytes: &[u8],
 ) -> Result<(VecDeque<Box<CLType>>, &[u8]), bytesrepr::Error> {
     let mut cl_types = VecDeque::with_capacity(count);
     for _ in 0..count {
-        let (cl_type, remainder) = CLType::from_bytes(bytes)?;
+        let (cl_type, remainder) = depth_limited_from_bytes(depth, bytes)?;
         cl_types.push_back(Box::new(cl_type));
         bytes = remainder;
     }
@@ -285,6 +660,124 @@ fn parse_cl_tuple_types(
     Ok((cl_types, bytes))
 }
 
+fn fmt_cl_tuple_type(f: &mut Formatter, cl_type_array: &[Box<CLType>]) -> fmt::Result {
+    write!(f, "Tuple{}<", cl_type_array.len())?;
+    for (index, cl_type) in cl_type_array.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", cl_type)?;
+    }
+    write!(f, ">")
+}
+
+fn fixed_serialized_size_of_cl_tuple_type(cl_type_array: &[Box<CLType>]) -> Option<usize> {
+    cl_type_array
+        .iter()
+        .map(|cl_type| cl_type.fixed_serialized_size())
+        .try_fold(0_usize, |total, maybe_size| total.checked_add(maybe_size?))
+}
+
+fn cl_types_match(lhs: &CLType, rhs: &CLType, any_matches_all: bool) -> bool {
+    match (lhs, rhs) {
+        (CLType::Any, _) | (_, CLType::Any) if any_matches_all => true,
+        (CLType::FixedList(inner, list_len), CLType::ByteArray(array_len))
+        | (CLType::ByteArray(array_len), CLType::FixedList(inner, list_len)) => {
+            let inner_matches = match **inner {
+                CLType::U8 => true,
+                CLType::Any => any_matches_all,
+                _ => false,
+            };
+            inner_matches && list_len == array_len
+        }
+        (CLType::Option(lhs), CLType::Option(rhs)) | (CLType::List(lhs), CLType::List(rhs)) => {
+            cl_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::FixedList(lhs, lhs_len), CLType::FixedList(rhs, rhs_len)) => {
+            lhs_len == rhs_len && cl_types_match(lhs, rhs, any_matches_all)
+        }
+        (
+            CLType::Result {
+                ok: lhs_ok,
+                err: lhs_err,
+            },
+            CLType::Result {
+                ok: rhs_ok,
+                err: rhs_err,
+            },
+        ) => {
+            cl_types_match(lhs_ok, rhs_ok, any_matches_all)
+                && cl_types_match(lhs_err, rhs_err, any_matches_all)
+        }
+        (
+            CLType::Map {
+                key: lhs_key,
+                value: lhs_value,
+            },
+            CLType::Map {
+                key: rhs_key,
+                value: rhs_value,
+            },
+        ) => {
+            cl_types_match(lhs_key, rhs_key, any_matches_all)
+                && cl_types_match(lhs_value, rhs_value, any_matches_all)
+        }
+        (CLType::Tuple1(lhs), CLType::Tuple1(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple2(lhs), CLType::Tuple2(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple3(lhs), CLType::Tuple3(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple4(lhs), CLType::Tuple4(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple5(lhs), CLType::Tuple5(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple6(lhs), CLType::Tuple6(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple7(lhs), CLType::Tuple7(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple8(lhs), CLType::Tuple8(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple9(lhs), CLType::Tuple9(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        (CLType::Tuple10(lhs), CLType::Tuple10(rhs)) => {
+            cl_tuple_types_match(lhs, rhs, any_matches_all)
+        }
+        _ => lhs == rhs,
+    }
+}
+
+fn cl_tuple_types_match(lhs: &[Box<CLType>], rhs: &[Box<CLType>], any_matches_all: bool) -> bool {
+    lhs.iter()
+        .zip(rhs)
+        .all(|(lhs, rhs)| cl_types_match(lhs, rhs, any_matches_all))
+}
+
+fn rust_tuple_type_string(cl_type_array: &[Box<CLType>]) -> String {
+    let mut output = String::from("(");
+    for (index, cl_type) in cl_type_array.iter().enumerate() {
+        if index > 0 {
+            output.push_str(", ");
+        }
+        output.push_str(&cl_type.to_rust_type_string());
+    }
+    // A 1-ary tuple requires a trailing comma to distinguish it from a parenthesized type.
+    if cl_type_array.len() == 1 {
+        output.push(',');
+    }
+    output.push(')');
+    output
+}
+
 fn serialized_length_of_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
     cl_type_array: T,
 ) -> usize {
@@ -336,6 +829,18 @@ impl CLTyped for u64 {
     }
 }
 
+impl CLTyped for f32 {
+    fn cl_type() -> CLType {
+        CLType::F32
+    }
+}
+
+impl CLTyped for f64 {
+    fn cl_type() -> CLType {
+        CLType::F64
+    }
+}
+
 impl CLTyped for U128 {
     fn cl_type() -> CLType {
         CLType::U128
@@ -401,7 +906,10 @@ macro_rules! impl_cl_typed_for_array {
         $(
             impl<T: CLTyped> CLTyped for [T; $N] {
                 fn cl_type() -> CLType {
-                    CLType::FixedList(Box::new(T::cl_type()), $N as u32)
+                    match T::cl_type() {
+                        CLType::U8 => CLType::ByteArray($N as u32),
+                        cl_type => CLType::FixedList(Box::new(cl_type), $N as u32),
+                    }
                 }
             }
         )+
@@ -454,14 +962,33 @@ impl<T1: CLTyped, T2: CLTyped, T3: CLTyped> CLTyped for (T1, T2, T3) {
     }
 }
 
+macro_rules! impl_cl_typed_for_tuple {
+    ($variant:ident, $($T:ident),+) => {
+        impl<$($T: CLTyped),+> CLTyped for ($($T,)+) {
+            fn cl_type() -> CLType {
+                CLType::$variant([$(Box::new($T::cl_type())),+])
+            }
+        }
+    };
+}
+
+impl_cl_typed_for_tuple!(Tuple4, T1, T2, T3, T4);
+impl_cl_typed_for_tuple!(Tuple5, T1, T2, T3, T4, T5);
+impl_cl_typed_for_tuple!(Tuple6, T1, T2, T3, T4, T5, T6);
+impl_cl_typed_for_tuple!(Tuple7, T1, T2, T3, T4, T5, T6, T7);
+impl_cl_typed_for_tuple!(Tuple8, T1, T2, T3, T4, T5, T6, T7, T8);
+impl_cl_typed_for_tuple!(Tuple9, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_cl_typed_for_tuple!(Tuple10, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+
 #[cfg(test)]
 mod tests {
     use std::{fmt::Debug, string::ToString};
 
     use super::*;
     use crate::{
+        account::AccountHash,
         bytesrepr::{FromBytes, ToBytes},
-        AccessRights, CLValue,
+        AccessRights, CLTypeMismatch, CLValue, CLValueError, Key, URef,
     };
 
     fn round_trip<T: CLTyped + FromBytes + ToBytes + PartialEq + Debug + Clone>(value: &T) {
@@ -507,6 +1034,27 @@ mod tests {
         round_trip(&-1i64);
     }
 
+    #[test]
+    fn f32_should_work() {
+        round_trip(&-1.5f32);
+        round_trip(&f32::INFINITY);
+    }
+
+    #[test]
+    fn f64_should_work() {
+        round_trip(&-1.5f64);
+        round_trip(&f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn nan_should_round_trip_bit_for_bit() {
+        let cl_value = CLValue::from_t(f64::NAN).unwrap();
+        let serialized_cl_value = cl_value.to_bytes().unwrap();
+        let parsed_cl_value: CLValue = bytesrepr::deserialize(serialized_cl_value).unwrap();
+        let parsed_value: f64 = parsed_cl_value.into_t().unwrap();
+        assert_eq!(f64::NAN.to_bits(), parsed_value.to_bits());
+    }
+
     #[test]
     fn u128_should_work() {
         round_trip(&U128::one());
@@ -612,6 +1160,101 @@ mod tests {
         test_large_array! { 64 128 256 512 }
     }
 
+    #[test]
+    fn byte_array_of_cl_type_should_work() {
+        assert_eq!(<[u8; 32]>::cl_type(), CLType::ByteArray(32));
+        round_trip(&[7u8; 0]);
+        round_trip(&[7u8; 32]);
+    }
+
+    #[test]
+    fn byte_array_should_serialize_more_compactly_than_fixed_list() {
+        let byte_array = [7u8; 32];
+        let cl_value = CLValue::from_t(byte_array).unwrap();
+        let legacy_cl_value = CLValue::from_components(
+            CLType::FixedList(Box::new(CLType::U8), 32),
+            byte_array.to_bytes().unwrap(),
+        );
+
+        // The value bytes are the same: 32 raw bytes with no length prefix.
+        assert_eq!(cl_value.inner_bytes().len(), 32);
+        assert_eq!(cl_value.inner_bytes(), legacy_cl_value.inner_bytes());
+
+        // The type is one byte shorter as it has no nested `U8` tag.
+        assert_eq!(CLType::ByteArray(32).serialized_length(), 5);
+        assert_eq!(
+            legacy_cl_value.cl_type().serialized_length(),
+            cl_value.cl_type().serialized_length() + 1
+        );
+        assert_eq!(
+            legacy_cl_value.to_bytes().unwrap().len(),
+            cl_value.to_bytes().unwrap().len() + 1
+        );
+    }
+
+    #[test]
+    fn legacy_fixed_list_of_u8_should_convert_to_byte_array() {
+        let byte_array = [7u8; 32];
+        let legacy_cl_value = CLValue::from_components(
+            CLType::FixedList(Box::new(CLType::U8), 32),
+            byte_array.to_bytes().unwrap(),
+        );
+        let parsed: [u8; 32] = legacy_cl_value.clone().into_t().unwrap();
+        assert_eq!(parsed, byte_array);
+
+        assert!(legacy_cl_value.into_t::<[u8; 31]>().is_err());
+    }
+
+    #[test]
+    fn into_t_should_convert_nested_legacy_byte_arrays() {
+        fn assert_converts<T>(value: T, legacy_cl_type: CLType)
+        where
+            T: CLTyped + FromBytes + ToBytes + PartialEq + Debug + Clone,
+        {
+            let bytes = value.to_bytes().unwrap();
+            let legacy_cl_value = CLValue::from_components(legacy_cl_type, bytes);
+            assert_eq!(legacy_cl_value.as_t::<T>(), Ok(value.clone()));
+            assert_eq!(legacy_cl_value.into_t::<T>(), Ok(value));
+        }
+
+        let legacy_byte_array = || Box::new(CLType::FixedList(Box::new(CLType::U8), 32));
+
+        assert_converts(
+            Some(AccountHash::new([1; 32])),
+            CLType::Option(legacy_byte_array()),
+        );
+        assert_converts(
+            vec![[1u8; 32], [2u8; 32]],
+            CLType::List(legacy_byte_array()),
+        );
+        let mut map = BTreeMap::new();
+        map.insert(String::from("a"), [3u8; 32]);
+        assert_converts(
+            map,
+            CLType::Map {
+                key: Box::new(CLType::String),
+                value: legacy_byte_array(),
+            },
+        );
+        assert_converts(
+            (7u8, [4u8; 32]),
+            CLType::Tuple2([Box::new(CLType::U8), legacy_byte_array()]),
+        );
+    }
+
+    #[test]
+    fn is_legacy_equivalent_should_not_treat_any_as_a_wildcard() {
+        let legacy_byte_array = CLType::FixedList(Box::new(CLType::U8), 32);
+        assert!(legacy_byte_array.is_legacy_equivalent(&CLType::ByteArray(32)));
+        assert!(CLType::Any.is_legacy_equivalent(&CLType::Any));
+
+        assert!(!CLType::Any.is_legacy_equivalent(&CLType::ByteArray(32)));
+        assert!(!CLType::List(Box::new(CLType::Any)).is_legacy_equivalent(&<Vec<u8>>::cl_type()));
+        assert!(!legacy_byte_array.is_legacy_equivalent(&CLType::ByteArray(31)));
+        assert!(!CLType::FixedList(Box::new(CLType::U32), 32)
+            .is_legacy_equivalent(&CLType::ByteArray(32)));
+    }
+
     #[test]
     fn result_of_cl_type_should_work() {
         let x: Result<(), String> = Ok(());
@@ -651,6 +1294,29 @@ mod tests {
         round_trip(&x);
     }
 
+    #[test]
+    fn tuple_7_should_work() {
+        let key = Key::URef(URef::new([0u8; 32], AccessRights::READ_ADD_WRITE));
+        let x = (
+            1u64,
+            String::from("a"),
+            key,
+            2u64,
+            String::from("b"),
+            Key::Hash([1u8; 32]),
+            3u64,
+        );
+
+        round_trip(&x);
+    }
+
+    #[test]
+    fn tuple_10_should_work() {
+        let x = (1u8, 2u32, 3u64, -4i32, -5i64, true, (), String::from("a"), 9u8, 10u32);
+
+        round_trip(&x);
+    }
+
     #[test]
     fn any_should_work() {
         #[derive(PartialEq, Debug, Clone)]
@@ -682,4 +1348,495 @@ mod tests {
         let any = Any("Any test".to_string());
         round_trip(&any);
     }
+
+    #[test]
+    fn should_display_nested_cl_types() {
+        assert_eq!(<Option<Vec<u8>>>::cl_type().to_string(), "Option<List<U8>>");
+        assert_eq!(<BTreeMap<String, Key>>::cl_type().to_string(), "Map<String, Key>");
+        assert_eq!(<(i32, String)>::cl_type().to_string(), "Tuple2<I32, String>");
+        assert_eq!(
+            <Result<[u64; 3], ()>>::cl_type().to_string(),
+            "Result<FixedList<U64, 3>, Unit>"
+        );
+        assert_eq!(<[u8; 32]>::cl_type().to_string(), "ByteArray<32>");
+        assert_eq!(
+            <(bool, Option<U512>, u8, u8)>::cl_type().to_string(),
+            "Tuple4<Bool, Option<U512>, U8, U8>"
+        );
+    }
+
+    #[test]
+    fn type_mismatch_should_display_cl_types() {
+        let cl_value = CLValue::from_t(U512::one()).unwrap();
+        match cl_value.into_t::<Option<U512>>() {
+            Err(CLValueError::Type(mismatch)) => assert_eq!(
+                mismatch.to_string(),
+                "Expected Option<U512> but found U512."
+            ),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn as_t_should_not_consume_cl_value() {
+        let value = vec![String::from("a"), String::from("b")];
+        let cl_value = CLValue::from_t(value.clone()).unwrap();
+
+        let first: Vec<String> = cl_value.as_t().unwrap();
+        let second: Vec<String> = cl_value.as_t().unwrap();
+        assert_eq!(first, value);
+        assert_eq!(second, value);
+        assert_eq!(cl_value.clone().into_t::<Vec<String>>().unwrap(), value);
+
+        match cl_value.as_t::<Vec<u8>>() {
+            Err(CLValueError::Type(mismatch)) => {
+                assert_eq!(mismatch.expected, CLType::List(Box::new(CLType::U8)));
+                assert_eq!(mismatch.found, *cl_value.cl_type());
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        let trailing_bytes = CLValue::from_components(CLType::U8, vec![1, 2]);
+        assert_eq!(
+            trailing_bytes.as_t::<u8>(),
+            Err(CLValueError::Serialization(bytesrepr::Error::LeftOverBytes))
+        );
+    }
+
+    #[test]
+    fn components_should_agree_with_destructure() {
+        let cl_value = CLValue::from_t((String::from("a"), Some(7u64))).unwrap();
+        let (cl_type, bytes) = cl_value.clone().destructure();
+        assert_eq!(cl_value.components(), (&cl_type, bytes.as_slice()));
+    }
+
+    #[test]
+    fn list_len_should_read_only_the_length_prefix() {
+        let strings = vec![String::from("a"), String::from("bc"), String::new()];
+        let cl_value = CLValue::from_t(strings).unwrap();
+        assert_eq!(cl_value.list_len(), Ok(Some(3)));
+
+        // The elements aren't parsed, so a truncated body doesn't prevent reading the length.
+        let cl_type = CLType::List(Box::new(CLType::String));
+        let truncated = CLValue::from_components(cl_type.clone(), 5u32.to_bytes().unwrap());
+        assert_eq!(truncated.list_len(), Ok(Some(5)));
+
+        let empty = CLValue::from_components(cl_type, vec![]);
+        assert_eq!(
+            empty.list_len(),
+            Err(CLValueError::Serialization(bytesrepr::Error::EarlyEndOfStream))
+        );
+        assert_eq!(CLValue::from_t(7u32).unwrap().list_len(), Ok(None));
+    }
+
+    #[test]
+    fn new_checked_should_validate_bytes_against_cl_type() {
+        let cl_value = CLValue::from_t((String::from("a"), Some(7u64))).unwrap();
+        let (cl_type, bytes) = cl_value.clone().destructure();
+        assert_eq!(CLValue::new_checked(cl_type.clone(), bytes.clone()), Ok(cl_value));
+
+        let mut trailing_bytes = bytes.clone();
+        trailing_bytes.push(0);
+        assert_eq!(
+            CLValue::new_checked(cl_type.clone(), trailing_bytes),
+            Err(CLValueError::Serialization(bytesrepr::Error::LeftOverBytes))
+        );
+
+        let short_bytes = bytes[..bytes.len() - 1].to_vec();
+        assert_eq!(
+            CLValue::new_checked(cl_type, short_bytes),
+            Err(CLValueError::Serialization(bytesrepr::Error::EarlyEndOfStream))
+        );
+
+        assert_eq!(
+            CLValue::new_checked(CLType::Bool, vec![2]),
+            Err(CLValueError::Serialization(bytesrepr::Error::Formatting))
+        );
+    }
+
+    #[test]
+    fn new_checked_should_not_iterate_over_zero_sized_elements() {
+        let max_count = u32::MAX.to_bytes().unwrap();
+        let list_of_units = CLType::List(Box::new(CLType::Unit));
+        assert!(CLValue::new_checked(list_of_units, max_count.clone()).is_ok());
+
+        let map_of_units = CLType::Map {
+            key: Box::new(CLType::Unit),
+            value: Box::new(CLType::Unit),
+        };
+        assert!(CLValue::new_checked(map_of_units, max_count).is_ok());
+
+        let fixed_list_of_units = CLType::FixedList(Box::new(CLType::Unit), u32::MAX);
+        assert!(CLValue::new_checked(fixed_list_of_units, vec![]).is_ok());
+    }
+
+    #[test]
+    fn new_checked_should_reject_count_exceeding_remaining_bytes() {
+        let mut bytes = u32::MAX.to_bytes().unwrap();
+        bytes.append(&mut 1u64.to_bytes().unwrap());
+        assert_eq!(
+            CLValue::new_checked(CLType::List(Box::new(CLType::U64)), bytes),
+            Err(CLValueError::Serialization(bytesrepr::Error::EarlyEndOfStream))
+        );
+
+        let fixed_list_of_bytes = CLType::FixedList(Box::new(CLType::U8), u32::MAX);
+        assert_eq!(
+            CLValue::new_checked(fixed_list_of_bytes, vec![1, 2, 3]),
+            Err(CLValueError::Serialization(bytesrepr::Error::EarlyEndOfStream))
+        );
+    }
+
+    #[test]
+    fn new_checked_should_accept_any_bytes_from_a_value_of_type_any_onwards() {
+        // The `Any` value could be of any length, so the trailing bytes may belong to it.
+        let any_then_byte = CLType::Tuple2([Box::new(CLType::Any), Box::new(CLType::U8)]);
+        assert!(CLValue::new_checked(any_then_byte.clone(), vec![1, 2, 3]).is_ok());
+        assert!(CLValue::new_checked(any_then_byte, vec![]).is_ok());
+
+        let mut list_of_any = 2u32.to_bytes().unwrap();
+        list_of_any.extend_from_slice(&[1, 2, 3, 4, 5]);
+        assert!(CLValue::new_checked(CLType::List(Box::new(CLType::Any)), list_of_any).is_ok());
+
+        // The bytes preceding the `Any` value are still checked.
+        let bool_then_any = CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::Any)]);
+        assert_eq!(
+            CLValue::new_checked(bool_then_any, vec![2, 1]),
+            Err(CLValueError::Serialization(bytesrepr::Error::Formatting))
+        );
+    }
+
+    #[test]
+    fn list_from_iter_should_match_from_t() {
+        let values: Vec<u64> = (0..100).collect();
+        let from_iter = CLValue::list_from_iter(values.iter().cloned()).unwrap();
+        let from_vec = CLValue::from_t(values.clone()).unwrap();
+        assert_eq!(from_iter.to_bytes().unwrap(), from_vec.to_bytes().unwrap());
+        assert_eq!(from_iter.into_t::<Vec<u64>>().unwrap(), values);
+
+        let urefs = vec![URef::new([1; 32], AccessRights::READ); 3];
+        let from_iter = CLValue::list_from_iter(urefs.clone().into_iter()).unwrap();
+        assert_eq!(from_iter, CLValue::from_t(urefs).unwrap());
+
+        let from_iter = CLValue::list_from_iter(Vec::<String>::new().into_iter()).unwrap();
+        assert_eq!(from_iter, CLValue::from_t(Vec::<String>::new()).unwrap());
+    }
+
+    #[test]
+    fn list_from_iter_should_fail_on_miscounted_iterator() {
+        /// An iterator which claims to yield `claimed` items, but actually yields `actual` items,
+        /// or yields items endlessly if `actual` is `None`.
+        struct Miscounted {
+            claimed: usize,
+            actual: Option<usize>,
+        }
+
+        impl Iterator for Miscounted {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                match self.actual.as_mut() {
+                    None => Some(1),
+                    Some(0) => None,
+                    Some(remaining) => {
+                        *remaining -= 1;
+                        Some(1)
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.claimed, Some(self.claimed))
+            }
+        }
+
+        impl ExactSizeIterator for Miscounted {}
+
+        let expected_error = Err(CLValueError::Serialization(bytesrepr::Error::Formatting));
+
+        // Should fail on the first surplus item rather than trying to drain the iterator.
+        let endless = Miscounted {
+            claimed: 2,
+            actual: None,
+        };
+        assert_eq!(CLValue::list_from_iter(endless), expected_error);
+
+        let too_short = Miscounted {
+            claimed: 3,
+            actual: Some(2),
+        };
+        assert_eq!(CLValue::list_from_iter(too_short), expected_error);
+    }
+
+    #[test]
+    fn cl_value_error_should_display_cause() {
+        let cl_value = CLValue::from_t(1u64).unwrap();
+        let type_error = cl_value.into_t::<String>().unwrap_err();
+        assert_eq!(type_error.to_string(), "CLValue type mismatch: Expected String but found U64.");
+
+        let cl_value = CLValue::from_components(CLType::U64, vec![1, 2]);
+        let serialization_error = cl_value.into_t::<u64>().unwrap_err();
+        assert_eq!(
+            serialization_error.to_string(),
+            "CLValue serialization error: Deserialization error: early end of stream"
+        );
+    }
+
+    #[test]
+    fn deeply_nested_cl_type_should_fail_to_parse() {
+        let mut bytes = vec![CL_TYPE_TAG_OPTION; 50_000];
+        bytes.push(CL_TYPE_TAG_BOOL);
+        assert_eq!(
+            bytesrepr::deserialize::<CLType>(bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn cl_type_at_recursion_limit_should_parse() {
+        let max_depth = CL_TYPE_RECURSION_DEPTH as usize;
+        let mut bytes = vec![CL_TYPE_TAG_OPTION; max_depth - 1];
+        bytes.push(CL_TYPE_TAG_BOOL);
+        let cl_type: CLType = bytesrepr::deserialize(bytes.clone()).unwrap();
+        let mut serialized = Vec::new();
+        cl_type.append_bytes(&mut serialized);
+        assert_eq!(serialized, bytes);
+
+        let mut too_deep_bytes = vec![CL_TYPE_TAG_OPTION; max_depth];
+        too_deep_bytes.push(CL_TYPE_TAG_BOOL);
+        assert_eq!(
+            bytesrepr::deserialize::<CLType>(too_deep_bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn contained_keys_should_find_urefs_in_map_values() {
+        let uref_1 = URef::new([1; 32], AccessRights::READ);
+        let uref_2 = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let mut map = BTreeMap::new();
+        map.insert(String::from("a"), Key::URef(uref_1));
+        map.insert(String::from("b"), Key::Hash([3; 32]));
+        map.insert(String::from("c"), Key::URef(uref_2));
+        let cl_value = CLValue::from_t(map.clone()).unwrap();
+
+        let keys = cl_value.contained_keys().unwrap();
+        assert_eq!(keys, map.values().cloned().collect::<Vec<_>>());
+        let urefs: Vec<URef> = keys.into_iter().filter_map(Key::into_uref).collect();
+        assert_eq!(urefs, vec![uref_1, uref_2]);
+    }
+
+    #[test]
+    fn contained_keys_should_find_nested_urefs() {
+        let uref = URef::new([1; 32], AccessRights::ADD);
+        let value = (Some(uref), vec![String::from("x")], Ok::<_, u64>(Key::URef(uref)));
+        let cl_value = CLValue::from_t(value).unwrap();
+        assert_eq!(cl_value.contained_keys().unwrap(), vec![Key::URef(uref), Key::URef(uref)]);
+
+        let cl_value = CLValue::from_t(42u64).unwrap();
+        assert!(cl_value.contained_keys().unwrap().is_empty());
+    }
+
+    #[test]
+    fn contained_keys_should_find_keys_in_nested_sequences() {
+        let uref_1 = URef::new([1; 32], AccessRights::READ);
+        let uref_2 = URef::new([2; 32], AccessRights::WRITE);
+        let hash = Key::Hash([3; 32]);
+
+        let nested = vec![
+            vec![Key::URef(uref_1)],
+            vec![],
+            vec![hash, Key::URef(uref_2), Key::URef(uref_1)],
+        ];
+        let cl_value = CLValue::from_t(nested.clone()).unwrap();
+        let expected: Vec<Key> = nested.into_iter().flatten().collect();
+        assert_eq!(cl_value.contained_keys().unwrap(), expected);
+
+        let optional_urefs = vec![None, Some(uref_2), None, Some(uref_1)];
+        let cl_value = CLValue::from_t(optional_urefs).unwrap();
+        assert_eq!(cl_value.contained_keys().unwrap(), vec![Key::URef(uref_2), Key::URef(uref_1)]);
+    }
+
+    #[test]
+    fn contained_keys_should_stop_at_a_value_of_type_any() {
+        let uref_1 = URef::new([1; 32], AccessRights::READ);
+        let uref_2 = URef::new([2; 32], AccessRights::WRITE);
+
+        let mut bytes = uref_1.to_bytes().unwrap();
+        bytes.append(&mut 7u64.to_bytes().unwrap());
+        bytes.append(&mut uref_2.to_bytes().unwrap());
+        let cl_type = CLType::Tuple3([
+            Box::new(CLType::URef),
+            Box::new(CLType::Any),
+            Box::new(CLType::URef),
+        ]);
+        let cl_value = CLValue::new_checked(cl_type, bytes).unwrap();
+        assert_eq!(cl_value.contained_keys().unwrap(), vec![Key::URef(uref_1)]);
+    }
+
+    #[test]
+    fn unit_cl_value_should_round_trip() {
+        let unit = CLValue::unit();
+        assert_eq!(unit, CLValue::from_t(()).unwrap());
+        assert!(unit.is_unit());
+        assert!(unit.cl_type_is(&CLType::Unit));
+        assert_eq!(unit.clone().into_t::<()>(), Ok(()));
+        assert_eq!(bytesrepr::deserialize::<CLValue>(unit.to_bytes().unwrap()), Ok(unit));
+
+        let byte = CLValue::from_t(0u8).unwrap();
+        assert!(!byte.is_unit());
+        assert!(byte.cl_type_is(&CLType::U8));
+        assert!(!byte.cl_type_is(&CLType::Unit));
+    }
+
+    #[test]
+    fn any_should_match_any_type() {
+        assert!(CLType::Any.matches(&CLType::U8));
+        assert!(CLType::U8.matches(&CLType::Any));
+        assert!(CLType::List(Box::new(CLType::Any)).matches(&<Vec<String>>::cl_type()));
+        let partly_any_tuple = CLType::Tuple2([Box::new(CLType::U8), Box::new(CLType::Any)]);
+        assert!(<(u8, Option<String>)>::cl_type().matches(&partly_any_tuple));
+        assert!(<BTreeMap<String, u64>>::cl_type().matches(&<BTreeMap<String, u64>>::cl_type()));
+
+        assert!(!CLType::U8.matches(&CLType::U32));
+        assert!(!CLType::List(Box::new(CLType::Any)).matches(&CLType::U8));
+        assert!(!<Option<u8>>::cl_type().matches(&<Option<u32>>::cl_type()));
+        assert!(!<Result<u8, String>>::cl_type().matches(&<Result<u8, u8>>::cl_type()));
+    }
+
+    #[test]
+    fn matches_should_treat_nested_legacy_byte_arrays_as_byte_arrays() {
+        let legacy_byte_array = || Box::new(CLType::FixedList(Box::new(CLType::U8), 32));
+
+        assert!(CLType::List(legacy_byte_array()).matches(&<Vec<[u8; 32]>>::cl_type()));
+        assert!(<Vec<[u8; 32]>>::cl_type().matches(&CLType::List(legacy_byte_array())));
+        assert!(CLType::Option(legacy_byte_array()).matches(&<Option<[u8; 32]>>::cl_type()));
+        let legacy_map = CLType::Map {
+            key: Box::new(CLType::String),
+            value: legacy_byte_array(),
+        };
+        assert!(legacy_map.matches(&<BTreeMap<String, [u8; 32]>>::cl_type()));
+        let legacy_tuple = CLType::Tuple2([Box::new(CLType::U8), legacy_byte_array()]);
+        assert!(legacy_tuple.matches(&<(u8, [u8; 32])>::cl_type()));
+
+        assert!(!CLType::List(legacy_byte_array()).matches(&<Vec<[u8; 31]>>::cl_type()));
+        let fixed_list_of_u32 = CLType::FixedList(Box::new(CLType::U32), 32);
+        assert!(!CLType::List(Box::new(fixed_list_of_u32)).matches(&<Vec<[u8; 32]>>::cl_type()));
+    }
+
+    #[test]
+    fn matches_should_treat_fixed_list_of_any_as_byte_array() {
+        let fixed_list_of_any = CLType::FixedList(Box::new(CLType::Any), 32);
+        assert!(fixed_list_of_any.matches(&CLType::ByteArray(32)));
+        assert!(CLType::ByteArray(32).matches(&fixed_list_of_any));
+        assert!(CLType::Option(Box::new(fixed_list_of_any.clone()))
+            .matches(&<Option<[u8; 32]>>::cl_type()));
+
+        assert!(!fixed_list_of_any.matches(&CLType::ByteArray(31)));
+        assert!(!fixed_list_of_any.is_legacy_equivalent(&CLType::ByteArray(32)));
+    }
+
+    #[test]
+    fn into_t_lenient_should_convert_nested_legacy_byte_arrays() {
+        let value = vec![[1u8; 32], [2u8; 32]];
+        let (_cl_type, bytes) = CLValue::from_t(value.clone()).unwrap().destructure();
+        let legacy_cl_type = CLType::List(Box::new(CLType::FixedList(Box::new(CLType::U8), 32)));
+        let legacy_cl_value = CLValue::from_components(legacy_cl_type, bytes);
+        assert_eq!(legacy_cl_value.into_t_lenient::<Vec<[u8; 32]>>(), Ok(value));
+    }
+
+    #[test]
+    fn into_t_lenient_should_convert_any_value() {
+        let value = CLValue::from_components(CLType::Any, 7u64.to_bytes().unwrap());
+        assert_eq!(value.clone().into_t_lenient::<u64>(), Ok(7));
+        assert!(matches!(value.into_t::<u64>(), Err(CLValueError::Type(_))));
+
+        let mismatched = CLValue::from_t(7u64).unwrap();
+        assert_eq!(
+            mismatched.into_t_lenient::<String>(),
+            Err(CLValueError::Type(CLTypeMismatch {
+                expected: CLType::String,
+                found: CLType::U64,
+            }))
+        );
+    }
+
+    #[test]
+    fn should_identify_primitive_types() {
+        assert!(CLType::Bool.is_primitive());
+        assert!(CLType::U512.is_primitive());
+        assert!(CLType::Unit.is_primitive());
+        assert!(CLType::Key.is_primitive());
+        assert!(CLType::URef.is_primitive());
+        assert!(!CLType::String.is_primitive());
+        assert!(!<Option<u8>>::cl_type().is_primitive());
+        assert!(!<(u8,)>::cl_type().is_primitive());
+        assert!(!CLType::Any.is_primitive());
+    }
+
+    fn check_fixed_serialized_size<T: CLTyped + ToBytes>(value: T) {
+        let expected = value.serialized_length();
+        assert_eq!(T::cl_type().fixed_serialized_size(), Some(expected));
+    }
+
+    #[test]
+    fn should_get_fixed_serialized_size() {
+        check_fixed_serialized_size(true);
+        check_fixed_serialized_size(-1_i32);
+        check_fixed_serialized_size(u64::max_value());
+        check_fixed_serialized_size(1.5_f64);
+        check_fixed_serialized_size(());
+        check_fixed_serialized_size(URef::new([1; 32], AccessRights::READ));
+        check_fixed_serialized_size([7_u8; 32]);
+        check_fixed_serialized_size([1_u32, 2, 3]);
+        check_fixed_serialized_size(Ok::<u32, i32>(1));
+        check_fixed_serialized_size((1_u8, (2_u32, (), 3_i64), [4_u8; 5]));
+        assert_eq!(<(u8, (u32, i64))>::cl_type().fixed_serialized_size(), Some(13));
+    }
+
+    #[test]
+    fn should_not_get_fixed_serialized_size_for_variable_types() {
+        assert_eq!(CLType::U512.fixed_serialized_size(), None);
+        assert_eq!(CLType::String.fixed_serialized_size(), None);
+        assert_eq!(CLType::Key.fixed_serialized_size(), None);
+        assert_eq!(<Option<u8>>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(<Vec<u8>>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(<BTreeMap<u8, u8>>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(<Result<u8, u32>>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(<(u8, String)>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(<[String; 2]>::cl_type().fixed_serialized_size(), None);
+        assert_eq!(CLType::Any.fixed_serialized_size(), None);
+    }
+
+    #[test]
+    fn should_render_rust_type_strings() {
+        assert_eq!(CLType::Unit.to_rust_type_string(), "()");
+        assert_eq!(<Vec<u8>>::cl_type().to_rust_type_string(), "Vec<u8>");
+        assert_eq!(<Option<U512>>::cl_type().to_rust_type_string(), "Option<U512>");
+        assert_eq!(<[u8; 32]>::cl_type().to_rust_type_string(), "[u8; 32]");
+        let legacy_byte_array = CLType::FixedList(Box::new(CLType::U8), 32);
+        assert_eq!(legacy_byte_array.to_rust_type_string(), "[u8; 32]");
+        assert_eq!(<[URef; 2]>::cl_type().to_rust_type_string(), "[URef; 2]");
+        assert_eq!(<Result<u64, String>>::cl_type().to_rust_type_string(), "Result<u64, String>");
+        assert_eq!(CLType::Any.to_rust_type_string(), "Box<dyn Any>");
+    }
+
+    #[test]
+    fn should_render_nested_rust_type_strings() {
+        assert_eq!(
+            <BTreeMap<String, Vec<Key>>>::cl_type().to_rust_type_string(),
+            "BTreeMap<String, Vec<Key>>"
+        );
+        assert_eq!(
+            <Option<Vec<(String, Key)>>>::cl_type().to_rust_type_string(),
+            "Option<Vec<(String, Key)>>"
+        );
+    }
+
+    #[test]
+    fn should_render_rust_tuple_type_strings() {
+        assert_eq!(<(bool,)>::cl_type().to_rust_type_string(), "(bool,)");
+        assert_eq!(<(i32, U128)>::cl_type().to_rust_type_string(), "(i32, U128)");
+        assert_eq!(<(u8, (), f64)>::cl_type().to_rust_type_string(), "(u8, (), f64)");
+        assert_eq!(<(u8, u8, u8, u8)>::cl_type().to_rust_type_string(), "(u8, u8, u8, u8)");
+    }
 }
@@ -1,11 +1,15 @@
-use alloc::vec::Vec;
-use core::fmt;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{cmp, convert::TryFrom, fmt};
 
 use failure::Fail;
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
-    CLType, CLTyped,
+    bytesrepr::{
+        self, FromBytes, ToBytes, BOOL_SERIALIZED_LENGTH, F32_SERIALIZED_LENGTH,
+        F64_SERIALIZED_LENGTH, I32_SERIALIZED_LENGTH, I64_SERIALIZED_LENGTH, U32_SERIALIZED_LENGTH,
+        U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH, UNIT_SERIALIZED_LENGTH,
+    },
+    CLType, CLTyped, Key, URef, KEY_HASH_LENGTH, U128, U256, U512, UREF_SERIALIZED_LENGTH,
 };
 
 /// Error while converting a [`CLValue`] into a given type.
@@ -20,11 +24,7 @@ pub struct CLTypeMismatch {
 
 impl fmt::Display for CLTypeMismatch {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            f,
-            "Expected {:?} but found {:?}.",
-            self.expected, self.found
-        )
+        write!(f, "Expected {} but found {}.", self.expected, self.found)
     }
 }
 
@@ -32,10 +32,10 @@ impl fmt::Display for CLTypeMismatch {
 #[derive(Fail, PartialEq, Eq, Clone, Debug)]
 pub enum CLValueError {
     /// An error while serializing or deserializing the underlying data.
-    #[fail(display = "CLValue error: {}", _0)]
+    #[fail(display = "CLValue serialization error: {}", _0)]
     Serialization(bytesrepr::Error),
     /// A type mismatch while trying to convert a [`CLValue`] into a given type.
-    #[fail(display = "Type mismatch: {}", _0)]
+    #[fail(display = "CLValue type mismatch: {}", _0)]
     Type(CLTypeMismatch),
 }
 
@@ -49,6 +49,174 @@ pub struct CLValue {
     bytes: Vec<u8>,
 }
 
+/// Parses a value of type `cl_type` from `bytes`, starting at `offset`, pushing any [`Key`]s and
+/// [`URef`]s it contains onto `keys`, and returns the offset of the end of the value.
+///
+/// The layout of a value of type `Any` is unknown, so parsing stops on reaching one and `None` is
+/// returned, as the end of the value can't be located.  Only the keys preceding it are collected.
+fn collect_keys(
+    cl_type: &CLType,
+    bytes: &[u8],
+    offset: usize,
+    keys: &mut Vec<Key>,
+) -> Result<Option<usize>, bytesrepr::Error> {
+    let remaining = &bytes[offset..];
+    let consumed = match cl_type {
+        CLType::Bool => bool::from_bytes_counted(remaining)?.1,
+        CLType::I32 => i32::from_bytes_counted(remaining)?.1,
+        CLType::I64 => i64::from_bytes_counted(remaining)?.1,
+        CLType::U8 => u8::from_bytes_counted(remaining)?.1,
+        CLType::U32 => u32::from_bytes_counted(remaining)?.1,
+        CLType::U64 => u64::from_bytes_counted(remaining)?.1,
+        CLType::F32 => f32::from_bytes_counted(remaining)?.1,
+        CLType::F64 => f64::from_bytes_counted(remaining)?.1,
+        CLType::U128 => U128::from_bytes_counted(remaining)?.1,
+        CLType::U256 => U256::from_bytes_counted(remaining)?.1,
+        CLType::U512 => U512::from_bytes_counted(remaining)?.1,
+        CLType::Unit => <()>::from_bytes_counted(remaining)?.1,
+        CLType::String => String::from_bytes_counted(remaining)?.1,
+        CLType::Key => {
+            let (key, consumed) = Key::from_bytes_counted(remaining)?;
+            keys.push(key);
+            consumed
+        }
+        CLType::URef => {
+            let (uref, consumed) = URef::from_bytes_counted(remaining)?;
+            keys.push(Key::URef(uref));
+            consumed
+        }
+        CLType::Option(inner) => match u8::from_bytes_counted(remaining)? {
+            (0, consumed) => consumed,
+            (1, consumed) => return collect_keys(inner, bytes, offset + consumed, keys),
+            _ => return Err(bytesrepr::Error::Formatting),
+        },
+        CLType::List(inner) => {
+            let (count, consumed) = u32::from_bytes_counted(remaining)?;
+            return collect_repeated_keys(&[inner.as_ref()], count, bytes, offset + consumed, keys);
+        }
+        CLType::FixedList(inner, len) => {
+            return collect_repeated_keys(&[inner.as_ref()], *len, bytes, offset, keys);
+        }
+        CLType::ByteArray(len) => bytesrepr::safe_split_at(remaining, *len as usize)?.0.len(),
+        CLType::Result { ok, err } => match u8::from_bytes_counted(remaining)? {
+            (0, consumed) => return collect_keys(err, bytes, offset + consumed, keys),
+            (1, consumed) => return collect_keys(ok, bytes, offset + consumed, keys),
+            _ => return Err(bytesrepr::Error::Formatting),
+        },
+        CLType::Map { key, value } => {
+            let (count, consumed) = u32::from_bytes_counted(remaining)?;
+            let types = [key.as_ref(), value.as_ref()];
+            return collect_repeated_keys(&types, count, bytes, offset + consumed, keys);
+        }
+        CLType::Tuple1(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple2(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple3(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple4(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple5(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple6(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple7(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple8(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple9(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Tuple10(types) => return collect_tuple_keys(types, bytes, offset, keys),
+        CLType::Any => return Ok(None),
+    };
+    Ok(Some(offset + consumed))
+}
+
+/// Collects the keys from `count` consecutive elements starting at `offset`, each comprising one
+/// value of each of `types` in turn, and returns the offset of the end of the last element.
+///
+/// `count` is untrusted, so is checked against the number of bytes remaining before iterating.  An
+/// element which can occupy no bytes holds no keys unless it contains a value of type `Any`, at
+/// which parsing stops.  Hence at most one such element is visited.
+fn collect_repeated_keys(
+    types: &[&CLType],
+    count: u32,
+    bytes: &[u8],
+    mut offset: usize,
+    keys: &mut Vec<Key>,
+) -> Result<Option<usize>, bytesrepr::Error> {
+    let element_min_length = types
+        .iter()
+        .map(|cl_type| min_serialized_length(cl_type))
+        .fold(0, usize::saturating_add);
+    let iterations = if element_min_length == 0 {
+        cmp::min(count, 1)
+    } else if count as usize > (bytes.len() - offset) / element_min_length {
+        return Err(bytesrepr::Error::EarlyEndOfStream);
+    } else {
+        count
+    };
+    for _ in 0..iterations {
+        for cl_type in types {
+            offset = match collect_keys(cl_type, bytes, offset, keys)? {
+                Some(end) => end,
+                None => return Ok(None),
+            };
+        }
+    }
+    Ok(Some(offset))
+}
+
+/// Returns the minimum number of bytes which a serialized value of type `cl_type` can occupy.
+fn min_serialized_length(cl_type: &CLType) -> usize {
+    match cl_type {
+        CLType::Bool => BOOL_SERIALIZED_LENGTH,
+        CLType::I32 => I32_SERIALIZED_LENGTH,
+        CLType::I64 => I64_SERIALIZED_LENGTH,
+        CLType::U8 => U8_SERIALIZED_LENGTH,
+        CLType::U32 => U32_SERIALIZED_LENGTH,
+        CLType::U64 => U64_SERIALIZED_LENGTH,
+        CLType::F32 => F32_SERIALIZED_LENGTH,
+        CLType::F64 => F64_SERIALIZED_LENGTH,
+        // Big integers, options and results all start with a one-byte prefix.
+        CLType::U128 | CLType::U256 | CLType::U512 | CLType::Option(_) | CLType::Result { .. } => {
+            U8_SERIALIZED_LENGTH
+        }
+        CLType::Unit => UNIT_SERIALIZED_LENGTH,
+        // Strings, lists and maps all start with a `u32` length prefix.
+        CLType::String | CLType::List(_) | CLType::Map { .. } => U32_SERIALIZED_LENGTH,
+        CLType::Key => KEY_HASH_LENGTH + U8_SERIALIZED_LENGTH,
+        CLType::URef => UREF_SERIALIZED_LENGTH,
+        CLType::FixedList(inner, len) => min_serialized_length(inner).saturating_mul(*len as usize),
+        CLType::ByteArray(len) => *len as usize,
+        CLType::Tuple1(types) => min_tuple_serialized_length(types),
+        CLType::Tuple2(types) => min_tuple_serialized_length(types),
+        CLType::Tuple3(types) => min_tuple_serialized_length(types),
+        CLType::Tuple4(types) => min_tuple_serialized_length(types),
+        CLType::Tuple5(types) => min_tuple_serialized_length(types),
+        CLType::Tuple6(types) => min_tuple_serialized_length(types),
+        CLType::Tuple7(types) => min_tuple_serialized_length(types),
+        CLType::Tuple8(types) => min_tuple_serialized_length(types),
+        CLType::Tuple9(types) => min_tuple_serialized_length(types),
+        CLType::Tuple10(types) => min_tuple_serialized_length(types),
+        // A value of type `Any` may occupy no bytes.
+        CLType::Any => 0,
+    }
+}
+
+fn min_tuple_serialized_length(types: &[Box<CLType>]) -> usize {
+    types
+        .iter()
+        .map(|cl_type| min_serialized_length(cl_type))
+        .fold(0, usize::saturating_add)
+}
+
+fn collect_tuple_keys(
+    types: &[Box<CLType>],
+    bytes: &[u8],
+    mut offset: usize,
+    keys: &mut Vec<Key>,
+) -> Result<Option<usize>, bytesrepr::Error> {
+    for cl_type in types {
+        offset = match collect_keys(cl_type, bytes, offset, keys)? {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(offset))
+}
+
 impl CLValue {
     /// Constructs a `CLValue` from `t`.
     pub fn from_t<T: CLTyped + ToBytes>(t: T) -> Result<CLValue, CLValueError> {
@@ -60,11 +228,71 @@ impl CLValue {
         })
     }
 
+    /// Constructs a `CLValue` holding the unit value `()`.
+    pub const fn unit() -> CLValue {
+        CLValue {
+            cl_type: CLType::Unit,
+            bytes: Vec::new(),
+        }
+    }
+
+    /// Constructs a `CLValue` holding a list of the items yielded by `iter`.
+    ///
+    /// Each item is serialized directly into the `CLValue`'s buffer, so the result is identical to
+    /// calling `CLValue::from_t(iter.collect::<Vec<T>>())` without the intermediate `Vec<T>`.
+    pub fn list_from_iter<T, I>(iter: I) -> Result<CLValue, CLValueError>
+    where
+        T: CLTyped + ToBytes,
+        I: ExactSizeIterator<Item = T>,
+    {
+        let count = u32::try_from(iter.len())
+            .map_err(|_| CLValueError::Serialization(bytesrepr::Error::OutOfMemory))?;
+        let mut bytes = count.into_bytes().map_err(CLValueError::Serialization)?;
+        // An `ExactSizeIterator` is not guaranteed to yield the number of items it reports.
+        let count_mismatch = CLValueError::Serialization(bytesrepr::Error::Formatting);
+        let mut actual_count = 0_u32;
+        for item in iter {
+            actual_count = actual_count
+                .checked_add(1)
+                .filter(|actual_count| *actual_count <= count)
+                .ok_or_else(|| count_mismatch.clone())?;
+            item.to_bytes_into(&mut bytes).map_err(CLValueError::Serialization)?;
+        }
+        if actual_count != count {
+            return Err(count_mismatch);
+        }
+
+        Ok(CLValue {
+            cl_type: CLType::List(Box::new(T::cl_type())),
+            bytes,
+        })
+    }
+
     /// Consumes and converts `self` back into its underlying type.
     pub fn into_t<T: CLTyped + FromBytes>(self) -> Result<T, CLValueError> {
         let expected = T::cl_type();
 
-        if self.cl_type == expected {
+        if self.cl_type.is_legacy_equivalent(&expected) {
+            bytesrepr::deserialize(self.bytes).map_err(CLValueError::Serialization)
+        } else {
+            Err(CLValueError::Type(CLTypeMismatch {
+                expected,
+                found: self.cl_type,
+            }))
+        }
+    }
+
+    /// Consumes and converts `self` into `T`, treating [`CLType::Any`] as matching any type (see
+    /// [`CLType::matches`]).
+    ///
+    /// This allows values whose type is only known at runtime to be converted.  [`into_t`] should
+    /// be preferred where the types must match exactly.
+    ///
+    /// [`into_t`]: CLValue::into_t
+    pub fn into_t_lenient<T: CLTyped + FromBytes>(self) -> Result<T, CLValueError> {
+        let expected = T::cl_type();
+
+        if self.cl_type.matches(&expected) {
             bytesrepr::deserialize(self.bytes).map_err(CLValueError::Serialization)
         } else {
             Err(CLValueError::Type(CLTypeMismatch {
@@ -74,6 +302,38 @@ impl CLValue {
         }
     }
 
+    /// Converts a copy of `self` into its underlying type, leaving `self` intact.
+    ///
+    /// This avoids cloning `self` when only read access to the underlying value is needed.
+    pub fn as_t<T: CLTyped + FromBytes>(&self) -> Result<T, CLValueError> {
+        let expected = T::cl_type();
+
+        if self.cl_type.is_legacy_equivalent(&expected) {
+            let (t, remainder) = T::from_bytes(&self.bytes).map_err(CLValueError::Serialization)?;
+            if remainder.is_empty() {
+                Ok(t)
+            } else {
+                Err(CLValueError::Serialization(bytesrepr::Error::LeftOverBytes))
+            }
+        } else {
+            Err(CLValueError::Type(CLTypeMismatch {
+                expected,
+                found: self.cl_type.clone(),
+            }))
+        }
+    }
+
+    /// Constructs a `CLValue` from `cl_type` and `bytes`, checking that `bytes` is exactly the
+    /// serialized form of a value of type `cl_type`.
+    ///
+    /// Values of type [`CLType::Any`] are opaque, so any bytes are accepted for them and for
+    /// everything following them in serialization order, e.g. `(Any, U8)` or `Vec<Any>`.
+    pub fn new_checked(cl_type: CLType, bytes: Vec<u8>) -> Result<CLValue, CLValueError> {
+        let cl_value = CLValue { cl_type, bytes };
+        cl_value.contained_keys()?;
+        Ok(cl_value)
+    }
+
     // This is only required in order to implement `TryFrom<state::CLValue> for CLValue` (i.e. the
     // conversion from the Protobuf `CLValue`) in a separate module to this one.
     #[doc(hidden)]
@@ -88,16 +348,64 @@ impl CLValue {
         (self.cl_type, self.bytes)
     }
 
+    /// Returns references to the [`CLType`] and the serialized form of the underlying data.
+    ///
+    /// This is the borrowing equivalent of `destructure()`.
+    pub fn components(&self) -> (&CLType, &[u8]) {
+        (&self.cl_type, &self.bytes)
+    }
+
     /// The [`CLType`] of the underlying data.
     pub fn cl_type(&self) -> &CLType {
         &self.cl_type
     }
 
+    /// Returns `true` if the underlying data is of type `cl_type`.
+    pub fn cl_type_is(&self, cl_type: &CLType) -> bool {
+        self.cl_type == *cl_type
+    }
+
+    /// Returns `true` if the underlying data is the unit value `()`.
+    pub fn is_unit(&self) -> bool {
+        self.cl_type == CLType::Unit
+    }
+
     /// Returns a reference to the serialized form of the underlying value held in this `CLValue`.
     pub fn inner_bytes(&self) -> &Vec<u8> {
         &self.bytes
     }
 
+    /// Returns all the [`Key`]s held anywhere in the underlying value, in serialization order.
+    ///
+    /// Bare [`URef`]s are returned as [`Key::URef`]s. Values of type [`CLType::Any`] are opaque,
+    /// so any keys within them, or following them in serialization order, are not found.
+    pub fn contained_keys(&self) -> Result<Vec<Key>, CLValueError> {
+        let mut keys = Vec::new();
+        let end = collect_keys(&self.cl_type, &self.bytes, 0, &mut keys)
+            .map_err(CLValueError::Serialization)?;
+        match end {
+            Some(end) if end != self.bytes.len() => {
+                Err(CLValueError::Serialization(bytesrepr::Error::LeftOverBytes))
+            }
+            _ => Ok(keys),
+        }
+    }
+
+    /// Returns the number of elements in the underlying value if it is a [`CLType::List`], or
+    /// `None` for any other type.
+    ///
+    /// Only the list's length prefix is read; the elements are not deserialized.
+    pub fn list_len(&self) -> Result<Option<u32>, CLValueError> {
+        match self.cl_type {
+            CLType::List(_) => {
+                let (len, _remainder) =
+                    u32::from_bytes(&self.bytes).map_err(CLValueError::Serialization)?;
+                Ok(Some(len))
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// Returns the length of the `Vec<u8>` yielded after calling `self.to_bytes()`.
     ///
     /// Note, this method doesn't actually serialize `self`, and hence is relatively cheap.
@@ -103,6 +103,8 @@ pub fn cl_simple_type_arb() -> impl Strategy<Value = CLType> {
         Just(CLType::U8),
         Just(CLType::U32),
         Just(CLType::U64),
+        Just(CLType::F32),
+        Just(CLType::F64),
         Just(CLType::U128),
         Just(CLType::U256),
         Just(CLType::U512),
@@ -126,6 +128,8 @@ pub fn cl_type_arb() -> impl Strategy<Value = CLType> {
             // Fixed lists of any size
             (element.clone(), 1u32..32u32)
                 .prop_map(|(cl_type, len)| CLType::FixedList(Box::new(cl_type), len)),
+            // Byte arrays of any size
+            (0u32..=512u32).prop_map(CLType::ByteArray),
             // Realistic Result type generator: ok is anything recursive, err is simple type
             (element.clone(), cl_simple_type_arb()).prop_map(|(ok, err)| CLType::Result {
                 ok: Box::new(ok),
@@ -144,13 +148,21 @@ pub fn cl_type_arb() -> impl Strategy<Value = CLType> {
                 Box::new(cl_type1),
                 Box::new(cl_type2)
             ])),
-            (element.clone(), element.clone(), element).prop_map(
+            (element.clone(), element.clone(), element.clone()).prop_map(
                 |(cl_type1, cl_type2, cl_type3)| CLType::Tuple3([
                     Box::new(cl_type1),
                     Box::new(cl_type2),
                     Box::new(cl_type3)
                 ])
             ),
+            (element.clone(), element.clone(), element.clone(), element).prop_map(
+                |(cl_type1, cl_type2, cl_type3, cl_type4)| CLType::Tuple4([
+                    Box::new(cl_type1),
+                    Box::new(cl_type2),
+                    Box::new(cl_type3),
+                    Box::new(cl_type4)
+                ])
+            ),
         ]
     })
 }
@@ -167,6 +179,8 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::U8
             | CLType::U32
             | CLType::U64
+            | CLType::F32
+            | CLType::F64
             | CLType::U128
             | CLType::U256
             | CLType::U512
@@ -177,11 +191,19 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::Option(_)
             | CLType::List(_)
             | CLType::FixedList(..)
+            | CLType::ByteArray(_)
             | CLType::Result { .. }
             | CLType::Map { .. }
             | CLType::Tuple1(_)
             | CLType::Tuple2(_)
             | CLType::Tuple3(_)
+            | CLType::Tuple4(_)
+            | CLType::Tuple5(_)
+            | CLType::Tuple6(_)
+            | CLType::Tuple7(_)
+            | CLType::Tuple8(_)
+            | CLType::Tuple9(_)
+            | CLType::Tuple10(_)
             | CLType::Any => (),
         }
     };
@@ -194,6 +216,8 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
         any::<u8>().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         any::<u32>().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         any::<u64>().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        any::<f32>().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        any::<f64>().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         u128_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         u256_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         u512_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
@@ -212,6 +236,10 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         (any::<bool>(), any::<i32>(), any::<i64>())
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        (any::<bool>(), any::<i32>(), any::<i64>(), any::<u8>())
+            .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        (any::<u64>(), ".*", key_arb(), any::<u64>(), ".*", key_arb(), any::<u64>())
+            .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
     ]
 }
 
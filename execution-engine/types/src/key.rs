@@ -1,18 +1,30 @@
-use alloc::{format, string::String, vec::Vec};
-use core::fmt::{self, Debug, Display, Formatter};
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+};
 
 use hex_fmt::HexFmt;
+#[cfg(feature = "serde")]
+use serde::{
+    de::Error as SerdeDeError, ser::Error as SerdeSerError, Deserialize, Deserializer, Serialize,
+    Serializer,
+};
 
 use crate::{
     account::AccountHash,
     bytesrepr::{self, Error, FromBytes, ToBytes},
-    URef, UREF_SERIALIZED_LENGTH,
+    URef, URefParseError, UREF_SERIALIZED_LENGTH,
 };
 
 const ACCOUNT_ID: u8 = 0;
 const HASH_ID: u8 = 1;
 const UREF_ID: u8 = 2;
 
+const ACCOUNT_STRING_PREFIX: &str = "account-account_hash-";
+const HASH_STRING_PREFIX: &str = "hash-";
+const UREF_STRING_PREFIX: &str = "uref-";
+
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
 /// The number of bytes in a [`Key::Hash`].
@@ -39,10 +51,42 @@ pub type ContractWasmHash = HashAddr;
 /// An alias for [`Key`]s hash variant.
 pub type ContractPackageHash = HashAddr;
 
+/// Error while parsing a [`Key`] from the string representation produced by [`Key::as_string`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum KeyParseError {
+    /// The string doesn't start with a prefix corresponding to any [`Key`] variant.
+    InvalidPrefix,
+    /// The hex-encoded portion of an account or hash key doesn't consist of exactly 64
+    /// characters; contains the actual length.
+    InvalidLength(usize),
+    /// The hex-encoded portion of an account or hash key isn't valid base16.
+    InvalidHex(base16::DecodeError),
+    /// The [`URef`] portion of a URef key couldn't be parsed.
+    URef(URefParseError),
+}
+
+impl From<URefParseError> for KeyParseError {
+    fn from(error: URefParseError) -> Self {
+        KeyParseError::URef(error)
+    }
+}
+
+/// Error while constructing a [`Key`] from a byte slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFromBytesError {
+    /// The slice doesn't have the required length.
+    InvalidLength {
+        /// The required length.
+        expected: usize,
+        /// The actual length of the slice.
+        actual: usize,
+    },
+}
+
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
 /// are indexed on the network.
 #[repr(C)]
-#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Key {
     /// A `Key` under which a user account is stored.
     Account(AccountHash),
@@ -78,6 +122,19 @@ impl Key {
         }
     }
 
+    /// Returns `true` if `self` and `other` are equal after both have been normalized (see
+    /// [`Key::normalize`]).
+    pub fn normalized_eq(&self, other: &Key) -> bool {
+        self.normalize() == other.normalize()
+    }
+
+    /// Removes from `keys` all entries which are equal under [`Key::normalized_eq`] to an earlier
+    /// entry, preserving the order of the retained keys.
+    pub fn dedup_normalized(keys: &mut Vec<Key>) {
+        let mut seen = BTreeSet::new();
+        keys.retain(|key| seen.insert(key.normalize()));
+    }
+
     /// Returns a human-readable version of `self`, with the inner bytes encoded to Base16.
     pub fn as_string(&self) -> String {
         match self {
@@ -90,6 +147,32 @@ impl Key {
         }
     }
 
+    /// Parses a string in the format produced by [`Key::as_string`] into a [`Key`].
+    pub fn from_formatted_str(input: &str) -> Result<Key, KeyParseError> {
+        if input.starts_with(ACCOUNT_STRING_PREFIX) {
+            let bytes = decode_32_bytes(&input[ACCOUNT_STRING_PREFIX.len()..])?;
+            Ok(Key::Account(AccountHash::new(bytes)))
+        } else if input.starts_with(HASH_STRING_PREFIX) {
+            let bytes = decode_32_bytes(&input[HASH_STRING_PREFIX.len()..])?;
+            Ok(Key::Hash(bytes))
+        } else if input.starts_with(UREF_STRING_PREFIX) {
+            Ok(Key::URef(URef::from_formatted_str(input)?))
+        } else {
+            Err(KeyParseError::InvalidPrefix)
+        }
+    }
+
+    /// Constructs a [`Key::Hash`] from `bytes`, which must be exactly [`KEY_HASH_LENGTH`] long.
+    pub fn hash_from_slice(bytes: &[u8]) -> Result<Key, KeyFromBytesError> {
+        copy_32_bytes(bytes).map(Key::Hash)
+    }
+
+    /// Constructs a [`Key::Account`] from `bytes`, which must be exactly
+    /// [`ACCOUNT_HASH_LENGTH`](crate::account::ACCOUNT_HASH_LENGTH) long.
+    pub fn account_from_slice(bytes: &[u8]) -> Result<Key, KeyFromBytesError> {
+        copy_32_bytes(bytes).map(|bytes| Key::Account(AccountHash::new(bytes)))
+    }
+
     /// Returns the inner bytes of `self` if `self` is of type [`Key::Account`], otherwise returns
     /// `None`.
     pub fn into_account(self) -> Option<AccountHash> {
@@ -125,6 +208,15 @@ impl Key {
         }
     }
 
+    /// Returns the tag identifying the variant of `self` in its serialized form.
+    fn tag(&self) -> u8 {
+        match self {
+            Key::Account(_) => ACCOUNT_ID,
+            Key::Hash(_) => HASH_ID,
+            Key::URef(_) => UREF_ID,
+        }
+    }
+
     /// Creates the seed of a local key for a context with the given base key.
     pub fn into_seed(self) -> [u8; BLAKE2B_DIGEST_LENGTH] {
         match self {
@@ -135,6 +227,27 @@ impl Key {
     }
 }
 
+fn copy_32_bytes(bytes: &[u8]) -> Result<[u8; KEY_HASH_LENGTH], KeyFromBytesError> {
+    if bytes.len() != KEY_HASH_LENGTH {
+        return Err(KeyFromBytesError::InvalidLength {
+            expected: KEY_HASH_LENGTH,
+            actual: bytes.len(),
+        });
+    }
+    let mut array = [0u8; KEY_HASH_LENGTH];
+    array.copy_from_slice(bytes);
+    Ok(array)
+}
+
+fn decode_32_bytes(hex: &str) -> Result<[u8; KEY_HASH_LENGTH], KeyParseError> {
+    if hex.len() != KEY_HASH_LENGTH * 2 {
+        return Err(KeyParseError::InvalidLength(hex.len()));
+    }
+    let mut bytes = [0u8; KEY_HASH_LENGTH];
+    let _bytes_written = base16::decode_slice(hex, &mut bytes).map_err(KeyParseError::InvalidHex)?;
+    Ok(bytes)
+}
+
 impl Display for Key {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -151,6 +264,28 @@ impl Debug for Key {
     }
 }
 
+/// `Key`s are ordered first by variant, in the order of their serialized tags (i.e. `Account`,
+/// `Hash`, then `URef`), and then by their inner values.
+///
+/// Maps keyed by `Key` are persisted in this order, so it must not be changed.  It is deliberately
+/// independent of the order in which the variants are declared.
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Key::Account(lhs), Key::Account(rhs)) => lhs.cmp(rhs),
+            (Key::Hash(lhs), Key::Hash(rhs)) => lhs.cmp(rhs),
+            (Key::URef(lhs), Key::URef(rhs)) => lhs.cmp(rhs),
+            _ => self.tag().cmp(&other.tag()),
+        }
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl From<URef> for Key {
     fn from(uref: URef) -> Key {
         Key::URef(uref)
@@ -215,6 +350,35 @@ impl FromBytes for Key {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Key {
+    /// Serializes as the string given by [`Key::as_string`] for human-readable formats, or as the
+    /// `bytesrepr` serialization of the key otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            self.as_string().serialize(serializer)
+        } else {
+            bytesrepr::ToBytes::to_bytes(self)
+                .map_err(SerdeSerError::custom)?
+                .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let formatted = String::deserialize(deserializer)?;
+            Key::from_formatted_str(&formatted)
+                .map_err(|error| SerdeDeError::custom(format!("{:?}", error)))
+        } else {
+            let bytes = Vec::<u8>::deserialize(deserializer)?;
+            bytesrepr::deserialize(bytes).map_err(SerdeDeError::custom)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,6 +387,30 @@ mod tests {
         AccessRights, URef,
     };
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn key_should_serialize_as_string_to_json() {
+        let key = Key::Hash([10u8; 32]);
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(
+            json,
+            "\"hash-0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a\""
+        );
+        let parsed: Key = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, key);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn key_should_serialize_as_bytesrepr_bytes_to_binary() {
+        let key = Key::Hash([10u8; 32]);
+        let serialized = bincode::serialize(&key).unwrap();
+        let expected = bincode::serialize(&bytesrepr::serialize(key).unwrap()).unwrap();
+        assert_eq!(serialized, expected);
+        let parsed: Key = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(parsed, key);
+    }
+
     fn test_readable(right: AccessRights, is_true: bool) {
         assert_eq!(right.is_readable(), is_true)
     }
@@ -295,9 +483,6 @@ mod tests {
         // Prefix is 2^32-1 = shouldn't allocate that much
         let bytes: Vec<u8> = vec![255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
         let res: Result<(Vec<Key>, &[u8]), _> = FromBytes::from_bytes(&bytes);
-        #[cfg(target_os = "linux")]
-        assert_eq!(res.expect_err("should fail"), Error::OutOfMemory);
-        #[cfg(target_os = "macos")]
         assert_eq!(res.expect_err("should fail"), Error::EarlyEndOfStream);
     }
 
@@ -329,6 +514,49 @@ mod tests {
         assert_eq!(key1.as_uref(), Some(&uref));
     }
 
+    #[test]
+    fn normalized_eq_should_ignore_uref_access_rights() {
+        let read_uref = Key::URef(URef::new([42; 32], AccessRights::READ));
+        let write_uref = Key::URef(URef::new([42; 32], AccessRights::WRITE));
+        let other_uref = Key::URef(URef::new([43; 32], AccessRights::READ));
+        assert!(read_uref.normalized_eq(&write_uref));
+        assert!(read_uref.normalized_eq(&read_uref.normalize()));
+        assert!(!read_uref.normalized_eq(&other_uref));
+
+        let hash = Key::Hash([42; KEY_HASH_LENGTH]);
+        assert!(hash.normalized_eq(&hash));
+        assert!(!hash.normalized_eq(&read_uref));
+    }
+
+    #[test]
+    fn dedup_normalized_should_preserve_first_seen_order() {
+        let read_uref = Key::URef(URef::new([42; 32], AccessRights::READ));
+        let write_uref = Key::URef(URef::new([42; 32], AccessRights::WRITE));
+        let other_uref = Key::URef(URef::new([1; 32], AccessRights::READ_ADD_WRITE));
+        let hash1 = Key::Hash([2; KEY_HASH_LENGTH]);
+        let hash2 = Key::Hash([3; KEY_HASH_LENGTH]);
+
+        let mut keys = vec![hash2, read_uref, hash1, write_uref, other_uref, hash2, read_uref];
+        Key::dedup_normalized(&mut keys);
+        assert_eq!(keys, vec![hash2, read_uref, hash1, other_uref]);
+    }
+
+    #[test]
+    fn key_ordering_should_be_stable() {
+        let account = Key::Account(AccountHash::new([255; BLAKE2B_DIGEST_LENGTH]));
+        let hash = Key::Hash([0; KEY_HASH_LENGTH]);
+        let uref = Key::URef(URef::new([0; 32], AccessRights::NONE));
+        let uref_with_rights = Key::URef(URef::new([0; 32], AccessRights::READ));
+        let larger_uref = Key::URef(URef::new([1; 32], AccessRights::NONE));
+
+        let mut keys = vec![larger_uref, uref_with_rights, hash, uref, account];
+        keys.sort();
+        assert_eq!(keys, vec![account, hash, uref, uref_with_rights, larger_uref]);
+
+        assert!(Key::Hash([1; KEY_HASH_LENGTH]) > hash);
+        assert!(Key::Account(AccountHash::new([0; BLAKE2B_DIGEST_LENGTH])) < account);
+    }
+
     #[test]
     fn key_max_serialized_length() {
         let key_account = Key::Account(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH]));
@@ -340,4 +568,57 @@ mod tests {
         let key_uref = Key::URef(URef::new([42; BLAKE2B_DIGEST_LENGTH], AccessRights::READ));
         assert!(key_uref.serialized_length() <= Key::max_serialized_length());
     }
+
+    #[test]
+    fn key_from_formatted_str_should_round_trip() {
+        let account_key = Key::Account(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH]));
+        assert_eq!(Key::from_formatted_str(&account_key.as_string()), Ok(account_key));
+
+        let hash_key = Key::Hash([43; KEY_HASH_LENGTH]);
+        assert_eq!(Key::from_formatted_str(&hash_key.as_string()), Ok(hash_key));
+
+        let uref_key = Key::URef(URef::new([44; 32], AccessRights::READ_ADD));
+        assert_eq!(Key::from_formatted_str(&uref_key.as_string()), Ok(uref_key));
+    }
+
+    #[test]
+    fn key_from_formatted_str_should_reject_invalid_input() {
+        assert_eq!(Key::from_formatted_str("local-00"), Err(KeyParseError::InvalidPrefix));
+        assert_eq!(Key::from_formatted_str("hash-00"), Err(KeyParseError::InvalidLength(2)));
+        assert!(matches!(
+            Key::from_formatted_str(&format!("account-account_hash-{}", "zz".repeat(32))),
+            Err(KeyParseError::InvalidHex(_))
+        ));
+        assert_eq!(
+            Key::from_formatted_str("uref-00-007"),
+            Err(KeyParseError::URef(URefParseError::InvalidAddressLength(2)))
+        );
+    }
+
+    #[test]
+    fn key_from_slice_should_validate_length() {
+        let bytes = [7u8; KEY_HASH_LENGTH + 1];
+
+        let exact = &bytes[..KEY_HASH_LENGTH];
+        assert_eq!(Key::hash_from_slice(exact), Ok(Key::Hash([7; KEY_HASH_LENGTH])));
+        assert_eq!(
+            Key::account_from_slice(exact),
+            Ok(Key::Account(AccountHash::new([7; KEY_HASH_LENGTH])))
+        );
+
+        let short_error = KeyFromBytesError::InvalidLength {
+            expected: KEY_HASH_LENGTH,
+            actual: KEY_HASH_LENGTH - 1,
+        };
+        let short = &bytes[..KEY_HASH_LENGTH - 1];
+        assert_eq!(Key::hash_from_slice(short), Err(short_error));
+        assert_eq!(Key::account_from_slice(short), Err(short_error));
+
+        let long_error = KeyFromBytesError::InvalidLength {
+            expected: KEY_HASH_LENGTH,
+            actual: KEY_HASH_LENGTH + 1,
+        };
+        assert_eq!(Key::hash_from_slice(&bytes), Err(long_error));
+        assert_eq!(Key::account_from_slice(&bytes), Err(long_error));
+    }
 }
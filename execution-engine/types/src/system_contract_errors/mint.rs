@@ -79,6 +79,8 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::Storage as u8 => Ok(Error::Storage),
+            d if d == Error::PurseNotFound as u8 => Ok(Error::PurseNotFound),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -129,3 +131,44 @@ impl fmt::Display for PurseError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::Error;
+    use crate::ApiError;
+
+    #[test]
+    fn all_variants_should_round_trip_via_api_error() {
+        // This match is deliberately exhaustive so that adding a variant fails to compile until
+        // this test, `TryFrom<u8> for Error` and the `ApiError` docs are updated.
+        fn expected_value(error: Error) -> u8 {
+            match error {
+                Error::InsufficientFunds => 0,
+                Error::SourceNotFound => 1,
+                Error::DestNotFound => 2,
+                Error::InvalidURef => 3,
+                Error::InvalidAccessRights => 4,
+                Error::InvalidNonEmptyPurseCreation => 5,
+                Error::Storage => 6,
+                Error::PurseNotFound => 7,
+            }
+        }
+
+        let mut variant_count = 0;
+        for value in 0..=u8::max_value() {
+            let error = match Error::try_from(value) {
+                Ok(error) => error,
+                Err(_) => continue,
+            };
+            assert_eq!(expected_value(error), value);
+
+            let api_error = ApiError::from(error);
+            assert_eq!(api_error, ApiError::Mint(value));
+            assert_eq!(ApiError::from(u32::from(api_error)), api_error);
+            variant_count += 1;
+        }
+        assert_eq!(variant_count, 8);
+    }
+}
@@ -2,7 +2,7 @@
 use failure::Fail;
 
 use alloc::vec::Vec;
-use core::result;
+use core::{convert::TryFrom, result};
 
 use crate::{
     bytesrepr::{self, ToBytes, U8_SERIALIZED_LENGTH},
@@ -113,6 +113,75 @@ impl CLTyped for Error {
     }
 }
 
+// This error type is not intended to be used by third party crates.
+#[doc(hidden)]
+pub struct TryFromU8ForError(());
+
+// This conversion is not intended to be used by third party crates.
+#[doc(hidden)]
+impl TryFrom<u8> for Error {
+    type Error = TryFromU8ForError;
+
+    fn try_from(value: u8) -> result::Result<Self, Self::Error> {
+        match value {
+            d if d == Error::NotBonded as u8 => Ok(Error::NotBonded),
+            d if d == Error::TooManyEventsInQueue as u8 => Ok(Error::TooManyEventsInQueue),
+            d if d == Error::CannotUnbondLastValidator as u8 => {
+                Ok(Error::CannotUnbondLastValidator)
+            }
+            d if d == Error::SpreadTooHigh as u8 => Ok(Error::SpreadTooHigh),
+            d if d == Error::MultipleRequests as u8 => Ok(Error::MultipleRequests),
+            d if d == Error::BondTooSmall as u8 => Ok(Error::BondTooSmall),
+            d if d == Error::BondTooLarge as u8 => Ok(Error::BondTooLarge),
+            d if d == Error::UnbondTooLarge as u8 => Ok(Error::UnbondTooLarge),
+            d if d == Error::BondTransferFailed as u8 => Ok(Error::BondTransferFailed),
+            d if d == Error::UnbondTransferFailed as u8 => Ok(Error::UnbondTransferFailed),
+            d if d == Error::TimeWentBackwards as u8 => Ok(Error::TimeWentBackwards),
+            d if d == Error::StakesNotFound as u8 => Ok(Error::StakesNotFound),
+            d if d == Error::PaymentPurseNotFound as u8 => Ok(Error::PaymentPurseNotFound),
+            d if d == Error::PaymentPurseKeyUnexpectedType as u8 => {
+                Ok(Error::PaymentPurseKeyUnexpectedType)
+            }
+            d if d == Error::PaymentPurseBalanceNotFound as u8 => {
+                Ok(Error::PaymentPurseBalanceNotFound)
+            }
+            d if d == Error::BondingPurseNotFound as u8 => Ok(Error::BondingPurseNotFound),
+            d if d == Error::BondingPurseKeyUnexpectedType as u8 => {
+                Ok(Error::BondingPurseKeyUnexpectedType)
+            }
+            d if d == Error::RefundPurseKeyUnexpectedType as u8 => {
+                Ok(Error::RefundPurseKeyUnexpectedType)
+            }
+            d if d == Error::RewardsPurseNotFound as u8 => Ok(Error::RewardsPurseNotFound),
+            d if d == Error::RewardsPurseKeyUnexpectedType as u8 => {
+                Ok(Error::RewardsPurseKeyUnexpectedType)
+            }
+            d if d == Error::StakesKeyDeserializationFailed as u8 => {
+                Ok(Error::StakesKeyDeserializationFailed)
+            }
+            d if d == Error::StakesDeserializationFailed as u8 => {
+                Ok(Error::StakesDeserializationFailed)
+            }
+            d if d == Error::SystemFunctionCalledByUserAccount as u8 => {
+                Ok(Error::SystemFunctionCalledByUserAccount)
+            }
+            d if d == Error::InsufficientPaymentForAmountSpent as u8 => {
+                Ok(Error::InsufficientPaymentForAmountSpent)
+            }
+            d if d == Error::FailedTransferToRewardsPurse as u8 => {
+                Ok(Error::FailedTransferToRewardsPurse)
+            }
+            d if d == Error::FailedTransferToAccountPurse as u8 => {
+                Ok(Error::FailedTransferToAccountPurse)
+            }
+            d if d == Error::SetRefundPurseCalledOutsidePayment as u8 => {
+                Ok(Error::SetRefundPurseCalledOutsidePayment)
+            }
+            _ => Err(TryFromU8ForError(())),
+        }
+    }
+}
+
 impl ToBytes for Error {
     fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
         let value = *self as u8;
@@ -158,3 +227,69 @@ impl PurseLookupError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::Error;
+    use crate::ApiError;
+
+    #[test]
+    fn all_variants_should_round_trip_via_api_error() {
+        // This match is deliberately exhaustive so that adding a variant fails to compile until
+        // this test, `TryFrom<u8> for Error` and the `ApiError` docs are updated.
+        fn expected_value(error: Error) -> u8 {
+            match error {
+                Error::NotBonded => 0,
+                Error::TooManyEventsInQueue => 1,
+                Error::CannotUnbondLastValidator => 2,
+                Error::SpreadTooHigh => 3,
+                Error::MultipleRequests => 4,
+                Error::BondTooSmall => 5,
+                Error::BondTooLarge => 6,
+                Error::UnbondTooLarge => 7,
+                Error::BondTransferFailed => 8,
+                Error::UnbondTransferFailed => 9,
+                Error::TimeWentBackwards => 10,
+                Error::StakesNotFound => 11,
+                Error::PaymentPurseNotFound => 12,
+                Error::PaymentPurseKeyUnexpectedType => 13,
+                Error::PaymentPurseBalanceNotFound => 14,
+                Error::BondingPurseNotFound => 15,
+                Error::BondingPurseKeyUnexpectedType => 16,
+                Error::RefundPurseKeyUnexpectedType => 17,
+                Error::RewardsPurseNotFound => 18,
+                Error::RewardsPurseKeyUnexpectedType => 19,
+                Error::StakesKeyDeserializationFailed => 20,
+                Error::StakesDeserializationFailed => 21,
+                Error::SystemFunctionCalledByUserAccount => 22,
+                Error::InsufficientPaymentForAmountSpent => 23,
+                Error::FailedTransferToRewardsPurse => 24,
+                Error::FailedTransferToAccountPurse => 25,
+                Error::SetRefundPurseCalledOutsidePayment => 26,
+            }
+        }
+
+        let mut variant_count = 0;
+        for value in 0..=u8::max_value() {
+            let error = match Error::try_from(value) {
+                Ok(error) => error,
+                Err(_) => continue,
+            };
+            assert_eq!(expected_value(error), value);
+
+            let api_error = ApiError::from(error);
+            assert_eq!(api_error, ApiError::ProofOfStake(value));
+            assert_eq!(ApiError::from(u32::from(api_error)), api_error);
+            variant_count += 1;
+        }
+        assert_eq!(variant_count, 27);
+    }
+
+    #[test]
+    fn out_of_range_value_should_fail_to_convert() {
+        assert!(Error::try_from(27).is_err());
+        assert!(Error::try_from(u8::max_value()).is_err());
+    }
+}
@@ -1,12 +1,26 @@
-use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "serde")]
+use alloc::format;
+use alloc::{string::String, vec::Vec};
 use core::{
     convert::TryFrom,
     fmt::{self, Debug, Display, Formatter},
+    str::{self, FromStr},
 };
 
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
 use hex_fmt::HexFmt;
+#[cfg(feature = "serde")]
+use serde::{
+    de::Error as SerdeDeError, ser::Error as SerdeSerError, Deserialize, Deserializer, Serialize,
+    Serializer,
+};
 
-use crate::{bytesrepr, AccessRights, ApiError, Key, ACCESS_RIGHTS_SERIALIZED_LENGTH};
+use crate::{
+    bytesrepr, AccessRights, AccessRightsParseError, ApiError, Key, ACCESS_RIGHTS_SERIALIZED_LENGTH,
+};
 
 /// The number of bytes in a [`URef`] address.
 pub const UREF_ADDR_LENGTH: usize = 32;
@@ -17,6 +31,40 @@ pub const UREF_SERIALIZED_LENGTH: usize = UREF_ADDR_LENGTH + ACCESS_RIGHTS_SERIA
 /// The address of a [`URef`](types::URef) (unforgeable reference) on the network.
 pub type URefAddr = [u8; UREF_ADDR_LENGTH];
 
+const FORMATTED_STRING_PREFIX: &str = "uref-";
+const ACCESS_RIGHTS_OCTAL_DIGITS: usize = 3;
+const FORMATTED_STRING_LENGTH: usize =
+    FORMATTED_STRING_PREFIX.len() + UREF_ADDR_LENGTH * 2 + 1 + ACCESS_RIGHTS_OCTAL_DIGITS;
+
+/// Error while parsing a [`URef`] from the string representation produced by
+/// [`URef::as_string`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum URefParseError {
+    /// The string doesn't start with `"uref-"`.
+    InvalidPrefix,
+    /// There is no `-` separating the address from the access rights.
+    MissingAccessRights,
+    /// The address doesn't consist of exactly 64 characters; contains the actual length.
+    InvalidAddressLength(usize),
+    /// The address isn't valid base16.
+    InvalidAddress(base16::DecodeError),
+    /// The access rights aren't exactly three octal digits.
+    InvalidAccessRightsFormat,
+    /// The access rights value doesn't correspond to valid [`AccessRights`]; contains the value.
+    InvalidAccessRights(u16),
+}
+
+impl From<AccessRightsParseError> for URefParseError {
+    fn from(error: AccessRightsParseError) -> Self {
+        match error {
+            AccessRightsParseError::InvalidFormat => URefParseError::InvalidAccessRightsFormat,
+            AccessRightsParseError::InvalidValue(value) => {
+                URefParseError::InvalidAccessRights(value)
+            }
+        }
+    }
+}
+
 /// Represents an unforgeable reference, containing an address in the network's global storage and
 /// the [`AccessRights`] of the reference.
 ///
@@ -50,6 +98,27 @@ impl URef {
         URef(self.0, AccessRights::NONE)
     }
 
+    /// Derives a child [`URef`] from this one's address and `index`, carrying this one's access
+    /// rights.
+    ///
+    /// The child's address is the BLAKE2b hash of this address followed by the little-endian bytes
+    /// of `index`. The derivation is deterministic, and since the hash is collision-resistant,
+    /// distinct `(parent, index)` pairs yield distinct child addresses.
+    pub fn derive_child(&self, index: u64) -> URef {
+        let mut hasher = VarBlake2b::new(UREF_ADDR_LENGTH)
+            .expect("UREF_ADDR_LENGTH should be a valid BLAKE2b digest length");
+        hasher.input(&self.0);
+        hasher.input(&index.to_le_bytes());
+        let mut addr = [0u8; UREF_ADDR_LENGTH];
+        hasher.variable_result(|hash| addr.clone_from_slice(hash));
+        URef(addr, self.1)
+    }
+
+    /// Returns the maximum size a [`URef`] can be serialized into.
+    pub const fn max_serialized_length() -> usize {
+        UREF_SERIALIZED_LENGTH
+    }
+
     /// Returns `true` if the access rights are `Some` and
     /// [`is_readable`](AccessRights::is_readable) is `true` for them.
     pub fn is_readable(self) -> bool {
@@ -82,15 +151,82 @@ impl URef {
     /// Formats the address and access rights of the [`URef`] in an unique way that could be used as
     /// a name when storing the given `URef` in a global state.
     pub fn as_string(&self) -> String {
+        let mut formatted = String::with_capacity(FORMATTED_STRING_LENGTH);
+        self.write_formatted(&mut formatted).expect("writing to a String should not fail");
+        formatted
+    }
+
+    /// Writes the same representation of the [`URef`] as is returned by [`URef::as_string`] to
+    /// `writer`, without allocating.
+    pub fn write_formatted<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        let mut hex_addr = [0u8; UREF_ADDR_LENGTH * 2];
+        let _bytes_written =
+            base16::encode_config_slice(&self.addr(), base16::EncodeLower, &mut hex_addr);
+        // Base16 encoding only yields ASCII characters, so this can't fail.
+        let hex_addr = str::from_utf8(&hex_addr).map_err(|_| fmt::Error)?;
         // Extract bits as numerical value, with no flags marked as 0.
         let access_rights_bits = self.access_rights().bits();
         // Access rights is represented as octal, which means that max value of u8 can
         // be represented as maximum of 3 octal digits.
-        format!(
-            "uref-{}-{:03o}",
-            base16::encode_lower(&self.addr()),
-            access_rights_bits
-        )
+        write!(writer, "{}{}-{:03o}", FORMATTED_STRING_PREFIX, hex_addr, access_rights_bits)
+    }
+
+    /// Formats only the address of the [`URef`] as `uref-<hex-encoded address>`.
+    ///
+    /// This is for lookups keyed by address alone, where the access rights are irrelevant: all
+    /// `URef`s with the same address yield the same string, whatever their access rights.
+    pub fn addr_as_string(&self) -> String {
+        let mut formatted = String::from(FORMATTED_STRING_PREFIX);
+        formatted.push_str(&base16::encode_lower(&self.addr()));
+        formatted
+    }
+
+    /// Parses a string in the format produced by [`URef::addr_as_string`] into a [`URefAddr`].
+    pub fn from_addr_string(input: &str) -> Result<URefAddr, URefParseError> {
+        if !input.starts_with(FORMATTED_STRING_PREFIX) {
+            return Err(URefParseError::InvalidPrefix);
+        }
+        parse_addr(&input[FORMATTED_STRING_PREFIX.len()..])
+    }
+
+    /// Parses a string in the format produced by [`URef::as_string`] into a [`URef`].
+    pub fn from_formatted_str(input: &str) -> Result<Self, URefParseError> {
+        if !input.starts_with(FORMATTED_STRING_PREFIX) {
+            return Err(URefParseError::InvalidPrefix);
+        }
+        let remainder = &input[FORMATTED_STRING_PREFIX.len()..];
+
+        let separator = remainder
+            .rfind('-')
+            .ok_or(URefParseError::MissingAccessRights)?;
+        let (addr_str, access_rights_str) = (&remainder[..separator], &remainder[separator + 1..]);
+
+        let addr = parse_addr(addr_str)?;
+
+        if access_rights_str.len() != ACCESS_RIGHTS_OCTAL_DIGITS {
+            return Err(URefParseError::InvalidAccessRightsFormat);
+        }
+        let access_rights = AccessRights::from_octal_str(access_rights_str)?;
+
+        Ok(URef(addr, access_rights))
+    }
+}
+
+fn parse_addr(addr_str: &str) -> Result<URefAddr, URefParseError> {
+    if addr_str.len() != UREF_ADDR_LENGTH * 2 {
+        return Err(URefParseError::InvalidAddressLength(addr_str.len()));
+    }
+    let mut addr = [0u8; UREF_ADDR_LENGTH];
+    let _bytes_written =
+        base16::decode_slice(addr_str, &mut addr).map_err(URefParseError::InvalidAddress)?;
+    Ok(addr)
+}
+
+impl FromStr for URef {
+    type Err = URefParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        URef::from_formatted_str(input)
     }
 }
 
@@ -113,6 +249,7 @@ impl bytesrepr::ToBytes for URef {
         let mut result = bytesrepr::unchecked_allocate_buffer(self);
         result.append(&mut self.0.to_bytes()?);
         result.append(&mut self.1.to_bytes()?);
+        debug_assert!(result.len() <= URef::max_serialized_length());
         Ok(result)
     }
 
@@ -141,10 +278,80 @@ impl TryFrom<Key> for URef {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for URef {
+    /// Serializes as the string given by [`URef::as_string`] for human-readable formats, or as the
+    /// `bytesrepr` serialization of the `URef` otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            self.as_string().serialize(serializer)
+        } else {
+            bytesrepr::ToBytes::to_bytes(self)
+                .map_err(SerdeSerError::custom)?
+                .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for URef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let formatted = String::deserialize(deserializer)?;
+            URef::from_formatted_str(&formatted)
+                .map_err(|error| SerdeDeError::custom(format!("{:?}", error)))
+        } else {
+            let bytes = Vec::<u8>::deserialize(deserializer)?;
+            bytesrepr::deserialize(bytes).map_err(SerdeDeError::custom)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uref_should_serialize_as_string_to_json() {
+        let uref = URef::new([10u8; 32], AccessRights::READ_ADD);
+        let json = serde_json::to_string(&uref).unwrap();
+        assert_eq!(
+            json,
+            "\"uref-0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a-005\""
+        );
+        let parsed: URef = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, uref);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uref_should_serialize_as_bytesrepr_bytes_to_binary() {
+        let uref = URef::new([10u8; 32], AccessRights::READ_ADD);
+        let serialized = bincode::serialize(&uref).unwrap();
+        let expected = bincode::serialize(&bytesrepr::serialize(uref).unwrap()).unwrap();
+        assert_eq!(serialized, expected);
+        let parsed: URef = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(parsed, uref);
+    }
+
+    #[test]
+    fn derive_child_should_be_deterministic() {
+        let parent = URef::new([7; UREF_ADDR_LENGTH], AccessRights::READ_ADD);
+        let child = parent.derive_child(0);
+        assert_eq!(child, parent.derive_child(0));
+        assert_eq!(child.access_rights(), AccessRights::READ_ADD);
+        assert_ne!(child.addr(), parent.addr());
+
+        assert_ne!(child.addr(), parent.derive_child(1).addr());
+        assert_ne!(child.addr(), parent.derive_child(u64::max_value()).addr());
+
+        let other_parent = URef::new([8; UREF_ADDR_LENGTH], AccessRights::READ_ADD);
+        assert_ne!(child.addr(), other_parent.derive_child(0).addr());
+    }
+
     #[test]
     fn uref_as_string() {
         // Since we are putting URefs to named_keys map keyed by the label that
@@ -168,4 +375,114 @@ mod tests {
             "uref-0000000000000000000000000000000000000000000000000000000000000000-000"
         );
     }
+
+    #[test]
+    fn uref_write_formatted_should_match_as_string() {
+        let uref = URef::new([0xab; UREF_ADDR_LENGTH], AccessRights::READ_ADD_WRITE);
+        let mut formatted = String::new();
+        uref.write_formatted(&mut formatted).unwrap();
+        assert_eq!(formatted, uref.as_string());
+        assert_eq!(formatted.len(), FORMATTED_STRING_LENGTH);
+    }
+
+    #[test]
+    fn uref_without_access_rights_should_serialize_with_zero_rights_byte() {
+        // Must match the serialization of a `URef` with `AccessRights.NONE` in contract-as.
+        let uref = URef::new([42; UREF_ADDR_LENGTH], AccessRights::READ_ADD_WRITE);
+        let serialized = bytesrepr::serialize(uref.remove_access_rights()).unwrap();
+        let mut expected = vec![42; UREF_ADDR_LENGTH];
+        expected.push(0);
+        assert_eq!(serialized, expected);
+        let parsed: URef = bytesrepr::deserialize(serialized).unwrap();
+        assert_eq!(parsed.access_rights(), AccessRights::NONE);
+    }
+
+    #[test]
+    fn uref_addr_as_string_should_round_trip_independent_of_access_rights() {
+        let addr = [42; UREF_ADDR_LENGTH];
+        let expected = "uref-2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a";
+        for bits in 0..=AccessRights::READ_ADD_WRITE.bits() {
+            let uref = URef::new(addr, AccessRights::from_bits(bits).unwrap());
+            let formatted = uref.addr_as_string();
+            assert_eq!(formatted, expected);
+            assert_eq!(URef::from_addr_string(&formatted), Ok(addr));
+        }
+    }
+
+    #[test]
+    fn uref_from_addr_string_should_reject_invalid_input() {
+        let uref = URef::new([1; UREF_ADDR_LENGTH], AccessRights::READ);
+        assert_eq!(
+            URef::from_addr_string(&uref.addr_as_string()[1..]),
+            Err(URefParseError::InvalidPrefix)
+        );
+        assert_eq!(
+            URef::from_addr_string(&uref.as_string()),
+            Err(URefParseError::InvalidAddressLength(68))
+        );
+        let invalid_hex = format!("uref-zz{}", "01".repeat(UREF_ADDR_LENGTH - 1));
+        assert!(matches!(
+            URef::from_addr_string(&invalid_hex),
+            Err(URefParseError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn uref_max_serialized_length() {
+        assert_eq!(URef::max_serialized_length(), UREF_SERIALIZED_LENGTH);
+        for bits in 0..=AccessRights::READ_ADD_WRITE.bits() {
+            let access_rights = AccessRights::from_bits(bits).unwrap();
+            let uref = URef::new([42; UREF_ADDR_LENGTH], access_rights);
+            let serialized = bytesrepr::serialize(uref).unwrap();
+            assert!(serialized.len() <= URef::max_serialized_length());
+        }
+    }
+
+    #[test]
+    fn uref_from_str_should_round_trip() {
+        let mut addr = [0u8; UREF_ADDR_LENGTH];
+        for (index, byte) in addr.iter_mut().enumerate() {
+            *byte = index as u8 * 7;
+        }
+        for bits in 0..=AccessRights::READ_ADD_WRITE.bits() {
+            let access_rights = AccessRights::from_bits(bits).unwrap();
+            let uref = URef::new(addr, access_rights);
+            let parsed: URef = uref.as_string().parse().unwrap();
+            assert_eq!(uref, parsed);
+        }
+    }
+
+    #[test]
+    fn uref_from_str_should_reject_invalid_input() {
+        let addr = "0000000000000000000000000000000000000000000000000000000000000000";
+
+        assert_eq!(
+            URef::from_formatted_str(&format!("hash-{}-007", addr)),
+            Err(URefParseError::InvalidPrefix)
+        );
+        assert_eq!(
+            URef::from_formatted_str("uref-0000"),
+            Err(URefParseError::MissingAccessRights)
+        );
+        assert_eq!(
+            URef::from_formatted_str("uref-00-007"),
+            Err(URefParseError::InvalidAddressLength(2))
+        );
+        assert!(matches!(
+            URef::from_formatted_str(&format!("uref-{}-007", addr.replace("0", "g"))),
+            Err(URefParseError::InvalidAddress(_))
+        ));
+        assert_eq!(
+            URef::from_formatted_str(&format!("uref-{}-07", addr)),
+            Err(URefParseError::InvalidAccessRightsFormat)
+        );
+        assert_eq!(
+            URef::from_formatted_str(&format!("uref-{}-008", addr)),
+            Err(URefParseError::InvalidAccessRightsFormat)
+        );
+        assert_eq!(
+            URef::from_formatted_str(&format!("uref-{}-010", addr)),
+            Err(URefParseError::InvalidAccessRights(8))
+        );
+    }
 }
@@ -19,24 +19,13 @@ function toValue<T>(set: (value: CLValueInstance, x: T) => void): ToValue<T> {
 }
 
 export const BytesValue = toValue<ByteArray>((value, x) => {
-  const innerType = new CLType();
-  innerType.setSimpleType(CLType.Simple.U8);
   const t = new CLType();
-  const fixedListType = new CLType.FixedList();
-  fixedListType.setInner(innerType);
-  fixedListType.setLen(x.length);
-  t.setFixedListType(fixedListType);
+  const byteArrayType = new CLType.ByteArray();
+  byteArrayType.setLen(x.length);
+  t.setByteArrayType(byteArrayType);
 
   const v = new CLValueInstance.Value();
-  const fixedListValue = new CLValueInstance.FixedList();
-  const bytes = Array.from(x, b => {
-    const byteValue = new CLValueInstance.Value();
-    byteValue.setU8(b);
-    return byteValue;
-  });
-  fixedListValue.setLength(bytes.length);
-  fixedListValue.setValuesList(bytes);
-  v.setFixedListValue(fixedListValue);
+  v.setBytesValue(x);
 
   value.setClType(t);
   value.setValue(v);
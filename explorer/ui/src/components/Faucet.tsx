@@ -161,8 +161,8 @@ const StatusCell = observer((props: { request: FaucetRequest }) => {
       if (failure) {
         const errm = failure.errorMessage;
         const hint =
-          errm === 'Exit code: 1'
-            ? '. It looks like you already funded this account!'
+          errm === 'User error: 2'
+            ? '. It looks like this account has already received the most the faucet gives out!'
             : errm === 'Exit code: 2'
               ? '. It looks like the faucet ran out of funds!'
               : '';
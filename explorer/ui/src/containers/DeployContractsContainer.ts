@@ -350,15 +350,12 @@ export class DeployContractsContainer {
   }
 
   private buildBytesTypeArg(argValueStr: string): CLValueInstance {
-    const innerType = new CLType();
-    innerType.setSimpleType(CLType.Simple.U8);
-    const fixedListType = new CLType.FixedList();
-    fixedListType.setInner(innerType);
+    const byteArrayType = new CLType.ByteArray();
     const bytes = decodeBase16(argValueStr);
-    fixedListType.setLen(bytes.length);
+    byteArrayType.setLen(bytes.length);
 
     const clType = new CLType();
-    clType.setFixedListType(fixedListType);
+    clType.setByteArrayType(byteArrayType);
 
     const value = new CLValueInstance.Value();
     value.setBytesValue(bytes);
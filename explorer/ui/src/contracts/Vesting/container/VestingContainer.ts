@@ -127,13 +127,14 @@ export class VestingContainer {
     );
   }
 
-  private isTypeOfU8FixedList(value: CLType) {
+  private isTypeOfByteArray(value: CLType) {
     return (
-      value.hasFixedListType() &&
-      this.isSimpleTypeOf(
-        value.getFixedListType()!.getInner()!,
-        CLType.Simple.U8
-      )
+      value.hasByteArrayType() ||
+      (value.hasFixedListType() &&
+        this.isSimpleTypeOf(
+          value.getFixedListType()!.getInner()!,
+          CLType.Simple.U8
+        ))
     );
   }
 
@@ -141,7 +142,7 @@ export class VestingContainer {
     return storedValues.map(v => {
       let value: number | ByteArray | boolean | null = null;
       let clType = v.getClValue()!.getClType()!;
-      if (this.isTypeOfU8FixedList(clType)) {
+      if (this.isTypeOfByteArray(clType)) {
         // parse publicKey
         value = v.getClValue()!.getValue()!.getBytesValue_asU8();
       } else if (this.isSimpleTypeOf(clType, CLType.Simple.BOOL)) {
@@ -47,6 +47,12 @@ object CLType {
   case object URef extends CLType {
     override def toString(): String = "URef";
   }
+  case object F32 extends CLType {
+    override def toString(): String = "F32";
+  }
+  case object F64 extends CLType {
+    override def toString(): String = "F64";
+  }
   case class Option(t: CLType) extends CLType {
     override def toString(): String = "Option(" + t.toString() + ")";
   }
@@ -77,10 +83,29 @@ object CLType {
   case object Any extends CLType {
     override def toString(): String = "Any";
   }
+  // A tuple of 4 to 10 elements. Use `TupleN.from` to check the arity when it is not known to be
+  // valid.
+  case class TupleN(types: immutable.List[CLType]) extends CLType {
+    override def toString(): String = "Tuple" + types.size + "(" + types.mkString(", ") + ")";
+
+    def tag: Byte = (CL_TYPE_TAG_TUPLE4 + types.size - TupleN.MinArity).toByte
+  }
+  object TupleN {
+    val MinArity = 4
+    val MaxArity = 10
+
+    def from(types: immutable.List[CLType]): scala.Option[TupleN] =
+      if (types.size >= MinArity && types.size <= MaxArity) Some(TupleN(types)) else None
+  }
+
+  // A fixed-length array of bytes, serialized identically to `FixedList(U8, length)`.
+  case class ByteArray(length: Int) extends CLType {
+    override def toString(): String = "ByteArray(" + length + ")";
+  }
 
   // Type representing the list of things that need to be appended to the
   // serialized CLType (see `toBytesTailRec` below). The `Left` case represents the
-  // length of a `FixedList`, while the `Right` case represents the inner type of
+  // length of a `FixedList` or `ByteArray`, while the `Right` case represents the inner type of
   // something like `List`, `Tuple2`, etc.
   private type LoopState = immutable.List[Either[Int, CLType]]
 
@@ -105,6 +130,8 @@ object CLType {
         case String => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_STRING)
         case Key    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_KEY)
         case URef   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_UREF)
+        case F32    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_F32)
+        case F64    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_F64)
 
         case Option(inner) => toBytesTailRec(Right(inner) :: tail, acc :+ CL_TYPE_TAG_OPTION)
 
@@ -128,6 +155,10 @@ object CLType {
           toBytesTailRec(Right(t1) :: Right(t2) :: Right(t3) :: tail, acc :+ CL_TYPE_TAG_TUPLE3)
 
         case Any => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_ANY)
+
+        case tuple @ TupleN(types) => toBytesTailRec(types.map(Right(_)) ::: tail, acc :+ tuple.tag)
+
+        case ByteArray(n) => toBytesTailRec(Left(n) :: tail, acc :+ CL_TYPE_TAG_BYTE_ARRAY)
       }
   }
 
@@ -187,6 +218,15 @@ object CLType {
         } yield Tuple3(t1, t2, t3)
 
       case tag if tag == CL_TYPE_TAG_ANY => FromBytes.pure(Any)
+      case tag if tag == CL_TYPE_TAG_F32 => FromBytes.pure(F32)
+      case tag if tag == CL_TYPE_TAG_F64 => FromBytes.pure(F64)
+
+      case tag if tag >= CL_TYPE_TAG_TUPLE4 && tag <= CL_TYPE_TAG_TUPLE10 =>
+        FromBytes
+          .fixedSeq(deserializer, tag - CL_TYPE_TAG_TUPLE4 + TupleN.MinArity)
+          .map(types => TupleN(types.toList))
+
+      case tag if tag == CL_TYPE_TAG_BYTE_ARRAY => FromBytes.int.map(length => ByteArray(length))
 
       case other => FromBytes.raise(FromBytes.Error.InvalidVariantTag(other, "CLType"))
     }
@@ -213,4 +253,9 @@ object CLType {
   val CL_TYPE_TAG_TUPLE2: Byte     = 19
   val CL_TYPE_TAG_TUPLE3: Byte     = 20
   val CL_TYPE_TAG_ANY: Byte        = 21
+  val CL_TYPE_TAG_F32: Byte        = 22
+  val CL_TYPE_TAG_F64: Byte        = 23
+  val CL_TYPE_TAG_TUPLE4: Byte     = 24
+  val CL_TYPE_TAG_TUPLE10: Byte    = 30
+  val CL_TYPE_TAG_BYTE_ARRAY: Byte = 31
 }
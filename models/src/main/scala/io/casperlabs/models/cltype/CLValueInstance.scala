@@ -67,6 +67,11 @@ object CLValueInstance {
         .fixedSeq(deserializer(inner), n)
         .flatMap(instances => lift(FixedList(instances, inner, n)))
 
+    case CLType.ByteArray(n) =>
+      FromBytes
+        .fixedSeq(FromBytes.byte, n)
+        .flatMap(bytes => lift(ByteArray(bytes, n)))
+
     case CLType.Result(ok, err) =>
       FromBytes
         .either(deserializer(err), deserializer(ok))
@@ -105,6 +110,17 @@ object CLValueInstance {
 
     case CLType.Any =>
       FromBytes.raise(FromBytes.Error.FormatException("Cannot instantiate CLType.Any"))
+
+    // Floats and tuples of more than three elements have no representation in the protobuf
+    // `CLValueInstance`, so they are not modelled as instances.
+    case CLType.F32 =>
+      FromBytes.raise(FromBytes.Error.FormatException("Cannot instantiate CLType.F32"))
+
+    case CLType.F64 =>
+      FromBytes.raise(FromBytes.Error.FormatException("Cannot instantiate CLType.F64"))
+
+    case CLType.TupleN(_) =>
+      FromBytes.raise(FromBytes.Error.FormatException("Cannot instantiate CLType.TupleN"))
   }
 
   case class Bool(value: Boolean) extends CLValueInstance {
@@ -206,6 +222,17 @@ object CLValueInstance {
       }
   }
 
+  case class ByteArray private (value: Seq[Byte], length: Int) extends CLValueInstance {
+    override val clType: CLType = CLType.ByteArray(length)
+  }
+  object ByteArray {
+    def apply(value: Seq[Byte], length: Int): Either[Error.InvalidLength, ByteArray] = {
+      val n = value.size
+      if (n != length) Left(Error.InvalidLength(valueLength = n, typeLength = length))
+      else Right(new ByteArray(value, length))
+    }
+  }
+
   case class Result private (
       value: Either[CLValueInstance, CLValueInstance],
       ok: CLType,
@@ -323,6 +350,14 @@ object CLValueInstance {
     case (FixedList(xs, _, _), FixedList(ys, _, _)) =>
       xs < ys
 
+    case (ByteArray(_, lx), ByteArray(_, ly)) if lx != ly =>
+      throw new IllegalArgumentException(
+        s"Incompatible element types: ByteArray($lx) != ByteArray($ly)"
+      )
+
+    case (ByteArray(xs, _), ByteArray(ys, _)) =>
+      xs < ys
+
     case (Result(_, txO, txE), Result(_, tyO, tyE)) if txO != tyO || txE != tyE =>
       throw new IllegalArgumentException(
         s"Incompatible element types: Result($txO, $txE) != Result($tyO, $tyE)"
@@ -408,6 +443,8 @@ object CLValueInstance {
 
       case CLValueInstance.FixedList(values, _, _) :: tail => valueBytes(values.toList ++ tail, acc)
 
+      case CLValueInstance.ByteArray(bytes, _) :: tail => valueBytes(tail, acc ++ bytes)
+
       case CLValueInstance.Result(Left(x), _, _) :: tail =>
         valueBytes(x :: tail, acc :+ Constants.Either.LEFT_TAG)
 
@@ -193,6 +193,8 @@ object Mappings {
     case CLType.String => Trampoline.done(dsl.types.string)
     case CLType.Key    => Trampoline.done(dsl.types.key)
     case CLType.URef   => Trampoline.done(dsl.types.uref)
+    case CLType.F32    => Trampoline.done(dsl.types.f32)
+    case CLType.F64    => Trampoline.done(dsl.types.f64)
 
     case CLType.Option(inner) =>
       Trampoline.defer(toProtoLoop(inner)).map(dsl.types.option)
@@ -203,6 +205,8 @@ object Mappings {
     case CLType.FixedList(inner, length) =>
       Trampoline.defer(toProtoLoop(inner)).map(dsl.types.fixedList(_, length))
 
+    case CLType.ByteArray(length) => Trampoline.done(dsl.types.byteArray(length))
+
     case CLType.Result(ok, err) =>
       for {
         okProto  <- Trampoline.defer(toProtoLoop(ok))
@@ -231,6 +235,9 @@ object Mappings {
         t3Proto <- toProtoLoop(t3)
       } yield dsl.types.tuple3(t1Proto, t2Proto, t3Proto)
 
+    case CLType.TupleN(types) =>
+      types.traverse(t => Trampoline.defer(toProtoLoop(t))).map(dsl.types.tupleN)
+
     case CLType.Any => Trampoline.done(dsl.types.any)
   }
 
@@ -282,6 +289,8 @@ object Mappings {
       case CLValueInstance.FixedList(values, _, _) =>
         values.toList.traverse(v => Trampoline.defer(toProtoValueLoop(v))).map(dsl.values.fixedList)
 
+      case CLValueInstance.ByteArray(bytes, _) => Trampoline.done(dsl.values.bytes(bytes))
+
       case CLValueInstance.Result(value, _, _) =>
         value
           .bitraverse(
@@ -382,6 +391,10 @@ object Mappings {
       pure(CLType.Key)
     case state.CLType(state.CLType.Variants.SimpleType(state.CLType.Simple.UREF)) =>
       pure(CLType.URef)
+    case state.CLType(state.CLType.Variants.SimpleType(state.CLType.Simple.F32)) =>
+      pure(CLType.F32)
+    case state.CLType(state.CLType.Variants.SimpleType(state.CLType.Simple.F64)) =>
+      pure(CLType.F64)
 
     case state.CLType(state.CLType.Variants.OptionType(state.CLType.OptionProto(innerProto))) =>
       innerProto match {
@@ -403,6 +416,9 @@ object Mappings {
         case Some(t) => defer(fromProtoLoop(t)).map(CLType.FixedList(_, length))
       }
 
+    case state.CLType(state.CLType.Variants.ByteArrayType(state.CLType.ByteArray(length))) =>
+      pure(CLType.ByteArray(length))
+
     case state.CLType(state.CLType.Variants.ResultType(state.CLType.Result(okProto, errProto))) =>
       for {
         ok  <- okProto.map(t => defer(fromProtoLoop(t))).getOrElse(raise(Error.MissingType))
@@ -436,6 +452,11 @@ object Mappings {
         t3 <- t3Proto.map(t => defer(fromProtoLoop(t))).getOrElse(raise(Error.MissingType))
       } yield CLType.Tuple3(t1, t2, t3)
 
+    case state.CLType(state.CLType.Variants.TupleNType(state.CLType.TupleN(typesProto))) =>
+      typesProto.toList.traverse(t => defer(fromProtoLoop(t))).flatMap { types =>
+        lift(CLType.TupleN.from(types).toRight(Error.InvalidTupleArity(types.size)))
+      }
+
     case state.CLType(state.CLType.Variants.AnyType(state.CLType.Any())) => pure(CLType.Any)
   }
 
@@ -484,7 +505,14 @@ object Mappings {
                 .leftMap(Error.InstanceError.apply)
             )
 
-          case other => raise(Error.TypeMismatch(other, "List(U8) or FixedList(U8)"))
+          case CLType.ByteArray(length) =>
+            lift(
+              CLValueInstance
+                .ByteArray(bytes.toByteArray, length)
+                .leftMap(Error.InstanceError.apply)
+            )
+
+          case other => raise(Error.TypeMismatch(other, "List(U8), FixedList(U8) or ByteArray"))
         }
 
       case state.CLValueInstance.Value.Value
@@ -686,6 +714,7 @@ object Mappings {
 
     case class InvalidBigIntValue(value: String) extends Error
     case class InvalidBitWidth(bitWidth: Int)    extends Error
+    case class InvalidTupleArity(arity: Int)     extends Error
 
     case class UnrecognizedAccessRights(enumValue: Int) extends Error
     case class UnrecognizedSimpleType(enumValue: Int)   extends Error
@@ -20,6 +20,8 @@ object dsl {
     val string: CLType = CLType(CLType.Variants.SimpleType(CLType.Simple.STRING))
     val key: CLType    = CLType(CLType.Variants.SimpleType(CLType.Simple.KEY))
     val uref: CLType   = CLType(CLType.Variants.SimpleType(CLType.Simple.UREF))
+    val f32: CLType    = CLType(CLType.Variants.SimpleType(CLType.Simple.F32))
+    val f64: CLType    = CLType(CLType.Variants.SimpleType(CLType.Simple.F64))
 
     def option(inner: CLType): CLType =
       CLType(CLType.Variants.OptionType(CLType.OptionProto(inner.some)))
@@ -59,6 +61,12 @@ object dsl {
           .Tuple3Type(CLType.Tuple3(t1.some, t2.some, t3.some))
       )
 
+    def tupleN(types: Seq[CLType]): CLType =
+      CLType(CLType.Variants.TupleNType(CLType.TupleN(types)))
+
+    def byteArray(length: Int): CLType =
+      CLType(CLType.Variants.ByteArrayType(CLType.ByteArray(length)))
+
     val any: CLType = CLType(CLType.Variants.AnyType(CLType.Any()))
   }
 
@@ -269,6 +277,11 @@ object dsl {
       value = values.bytes(bs).some
     )
 
+    def byteArray(bs: Seq[Byte]): CLValueInstance = CLValueInstance(
+      clType = types.byteArray(bs.size).some,
+      value = values.bytes(bs).some
+    )
+
     object option {
       def some(element: CLValueInstance): CLValueInstance = CLValueInstance(
         clType = element.clType.map(types.option),
@@ -7,7 +7,7 @@ import org.scalatest.{FlatSpec, Matchers}
 import org.scalatest.prop.PropertyChecks
 import CLTypeSerializationTest.{arbCLType, bytesRoundTrip, nested}
 
-class CLTypeSerializationTest extends FlatSpec with PropertyChecks {
+class CLTypeSerializationTest extends FlatSpec with Matchers with PropertyChecks {
   "CLTypes" should "serialize properly" in forAll { (t: CLType) =>
     roundTrip(t, CLType.deserializer)
   }
@@ -27,11 +27,33 @@ class CLTypeSerializationTest extends FlatSpec with PropertyChecks {
 
     bytesRoundTrip(widerType, CLType.deserializer)
   }
+
+  it should "use the same tags as the execution engine for floats" in {
+    ToBytes.toBytes[CLType](CLType.F32).toSeq shouldBe Seq[Byte](22)
+    ToBytes.toBytes[CLType](CLType.F64).toSeq shouldBe Seq[Byte](23)
+  }
+
+  it should "use the same tags as the execution engine for tuples of 4 to 10 elements" in {
+    val tuple4 = CLType.TupleN(List(CLType.U8, CLType.U8, CLType.U8, CLType.Bool))
+    ToBytes.toBytes[CLType](tuple4).toSeq shouldBe Seq[Byte](24, 3, 3, 3, 0)
+
+    val tuple10 = CLType.TupleN(List.fill(10)(CLType.Unit))
+    ToBytes.toBytes[CLType](tuple10).head shouldBe 30.toByte
+  }
+
+  it should "use the same tag as the execution engine for byte arrays" in {
+    ToBytes.toBytes[CLType](CLType.ByteArray(32)).toSeq shouldBe Seq[Byte](31, 32, 0, 0, 0)
+  }
+
+  it should "only construct tuples of 4 to 10 elements via TupleN.from" in {
+    CLType.TupleN.from(List(CLType.U8, CLType.U8, CLType.U8)) shouldBe None
+    CLType.TupleN.from(List.fill(11)(CLType.U8)) shouldBe None
+  }
 }
 
 object CLTypeSerializationTest extends Matchers {
 
-  def genCLType: Gen[CLType] = Gen.choose(0, 21).flatMap {
+  def genCLType: Gen[CLType] = Gen.choose(0, 31).flatMap {
     case 0  => Gen.const(CLType.Bool)
     case 1  => Gen.const(CLType.I32)
     case 2  => Gen.const(CLType.I64)
@@ -83,11 +105,32 @@ object CLTypeSerializationTest extends Matchers {
       } yield CLType.Tuple3(t1, t2, t3)
 
     case 21 => Gen.const(CLType.Any)
+    case 22 => Gen.const(CLType.F32)
+    case 23 => Gen.const(CLType.F64)
 
-    // this should never happen since we generate from 0 to 21
+    // Elements are kept simple, since generating up to ten nested types per tuple would make the
+    // generated types unboundedly large.
+    case n if n >= 24 && n <= 30 =>
+      Gen.listOfN(n - 20, genSimpleCLType).map(types => CLType.TupleN(types))
+
+    case 31 => Gen.choose(0, 100).map(n => CLType.ByteArray(n))
+
+    // this should never happen since we generate from 0 to 31
     case _ => Gen.fail
   }
 
+  def genSimpleCLType: Gen[CLType] = Gen.oneOf(
+    CLType.Bool,
+    CLType.I32,
+    CLType.U64,
+    CLType.U512,
+    CLType.Unit,
+    CLType.String,
+    CLType.Key,
+    CLType.URef,
+    CLType.Any
+  )
+
   implicit val arbCLType: Arbitrary[CLType] = Arbitrary(genCLType)
 
   def nested(base: CLType, n: Int)(nest: CLType => CLType): CLType = (1 to n).foldLeft(base) {
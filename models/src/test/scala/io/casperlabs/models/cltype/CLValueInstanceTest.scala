@@ -163,6 +163,21 @@ class CLValueInstanceTest extends FlatSpec with Matchers with PropertyChecks {
     )(Functor[List], toBytesFixedList[String])
   }
 
+  it should "instantiate CLType.ByteArray properly" in forAll { (list: List[Byte]) =>
+    val n = list.size
+    instantiateTest[List[Byte]](
+      list,
+      CLType.ByteArray(n),
+      x => CLValueInstance.ByteArray(x, n).right.get
+    )(toBytesFixedList[Byte])
+  }
+
+  it should "reject a CLType.ByteArray instance of the wrong length" in {
+    CLValueInstance.ByteArray(List[Byte](1, 2, 3), 4) shouldBe Left(
+      CLValueInstance.Error.InvalidLength(valueLength = 3, typeLength = 4)
+    )
+  }
+
   it should "instantiate CLType.Result properly" in forAll { (e: Either[String, URef]) =>
     instantiateTest[Either[String, URef]](
       e,
@@ -307,7 +322,7 @@ class CLValueInstanceTest extends FlatSpec with Matchers with PropertyChecks {
 }
 
 object CLValueInstanceTest {
-  def genCLInstance: Gen[CLValueInstance] = Gen.choose(0, 20).flatMap {
+  def genCLInstance: Gen[CLValueInstance] = Gen.choose(0, 21).flatMap {
     case 0 => Gen.oneOf(true, false).map(CLValueInstance.Bool.apply)
     case 1 => Gen.chooseNum(-1000, 1000).map(CLValueInstance.I32.apply)
     case 2 => Gen.chooseNum(-10000L, 10000L).map(CLValueInstance.I64.apply)
@@ -392,6 +407,12 @@ object CLValueInstanceTest {
         t3 <- genCLInstance
       } yield CLValueInstance.Tuple3(t1, t2, t3)
 
+    case 21 =>
+      for {
+        n     <- Gen.choose(0, 64)
+        bytes <- Gen.listOfN(n, arbitrary[Byte])
+      } yield CLValueInstance.ByteArray(bytes, n).right.get
+
     // this should never happen since we generate from 0 to 21
     case _ => Gen.fail
   }
@@ -31,6 +31,14 @@ class MappingsTest extends FlatSpec with Matchers with PropertyChecks {
     val _         = Mappings.fromProto(wideProto)
   }
 
+  it should "reject tuples of an unsupported arity" in {
+    val tooShort = dsl.types.tupleN(List(dsl.types.u8, dsl.types.u8, dsl.types.u8))
+    Mappings.fromProto(tooShort) shouldBe Left(Mappings.Error.InvalidTupleArity(3))
+
+    val tooLong = dsl.types.tupleN(List.fill(11)(dsl.types.unit))
+    Mappings.fromProto(tooLong) shouldBe Left(Mappings.Error.InvalidTupleArity(11))
+  }
+
   "CLValueInstance to/fromProto" should "work" in forAll { (v: CLValueInstance) =>
     val p = Mappings.toProto(v)
     val q = Mappings.fromProto(p)
@@ -63,4 +71,13 @@ class MappingsTest extends FlatSpec with Matchers with PropertyChecks {
     Mappings.toProto(list) shouldBe dsl.instances.bytes(bytes)
     Mappings.toProto(fixedList) shouldBe dsl.instances.bytesFixedLength(bytes)
   }
+
+  "CLValueInstance.ByteArray" should "convert to and from bytes" in {
+    val bytes     = Array.range(0, 32).map(_.toByte)
+    val byteArray = CLValueInstance.ByteArray(bytes, bytes.length).right.get
+    val proto     = Mappings.toProto(byteArray)
+
+    proto shouldBe dsl.instances.byteArray(bytes)
+    Mappings.fromProto(proto) shouldBe Right(byteArray)
+  }
 }
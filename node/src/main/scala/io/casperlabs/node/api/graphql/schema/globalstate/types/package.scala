@@ -386,6 +386,14 @@ package object types {
       )
   )
 
+  lazy val ByteArray = ObjectType(
+    "ByteArray",
+    fields[Unit, CLValueInstance.ByteArray](
+      Field("value", StringType, resolve = c => Base16.encode(c.value.value.toArray)),
+      Field("length", IntType, resolve = _.value.length)
+    )
+  )
+
   lazy val ResultUnion = UnionType(
     "Either",
     types = List(
@@ -473,6 +481,7 @@ package object types {
       CLOption,
       CLList,
       FixedList,
+      ByteArray,
       Result,
       CLMap,
       Tuple1,
@@ -504,6 +513,7 @@ package object types {
       CLOption,
       CLList,
       FixedList,
+      ByteArray,
       Result,
       CLMap,
       Tuple1,
@@ -541,6 +551,7 @@ package object types {
               case v: CLValueInstance.Option    => v
               case v: CLValueInstance.List      => v
               case v: CLValueInstance.FixedList => v
+              case v: CLValueInstance.ByteArray => v
               case v: CLValueInstance.Result    => v
               case v: CLValueInstance.Map       => v
               case v: CLValueInstance.Tuple1    => v
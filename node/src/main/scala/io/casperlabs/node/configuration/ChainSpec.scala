@@ -139,7 +139,10 @@ object ChainSpec extends ParserImplicits {
       memCopyPerByte: Int Refined NonNegative,
       maxStackHeight: Int Refined NonNegative,
       opcodesMultiplier: Int Refined NonNegative,
-      opcodesDivisor: Int Refined Positive
+      opcodesDivisor: Int Refined Positive,
+      controlFlow: Int Refined NonNegative,
+      bit: Int Refined NonNegative,
+      hostFunctionBase: Int Refined NonNegative
   ) extends SubConfig
 
   final case class Account(
@@ -425,6 +428,9 @@ object ChainSpecReader {
           .withMaxStackHeight(wasmCosts.maxStackHeight.value)
           .withOpcodesMul(wasmCosts.opcodesMultiplier.value)
           .withOpcodesDiv(wasmCosts.opcodesDivisor.value)
+          .withControlFlow(wasmCosts.controlFlow.value)
+          .withBit(wasmCosts.bit.value)
+          .withHostFunctionBase(wasmCosts.hostFunctionBase.value)
       )
 
   private def toDeployConfig(deployConfig: Deploy): ipc.ChainSpec.DeployConfig =
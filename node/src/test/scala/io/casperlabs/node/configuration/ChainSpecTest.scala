@@ -150,6 +150,9 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
           wasmCosts.maxStackHeight shouldBe 8
           wasmCosts.opcodesMul shouldBe 9
           wasmCosts.opcodesDiv shouldBe 10
+          wasmCosts.controlFlow shouldBe 11
+          wasmCosts.bit shouldBe 12
+          wasmCosts.hostFunctionBase shouldBe 13
         }
       }
 
@@ -174,6 +177,9 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
           wasmCosts.maxStackHeight shouldBe 28
           wasmCosts.opcodesMul shouldBe 29
           wasmCosts.opcodesDiv shouldBe 210
+          wasmCosts.controlFlow shouldBe 211
+          wasmCosts.bit shouldBe 212
+          wasmCosts.hostFunctionBase shouldBe 213
         }
       }
 